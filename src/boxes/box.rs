@@ -11,8 +11,21 @@ pub trait Box: Collection {
     fn from_time<Tz: TimeZone>(time: DateTime<Tz>) -> Self;
     fn from_temporal_span(span: TsTzSpan) -> Self;
     fn tstzspan(&self) -> TsTzSpan;
-    fn as_wkb(&self, variant: WKBVariant) -> &[u8];
-    fn as_hexwkb(&self, variant: WKBVariant) -> &[u8];
+    fn as_wkb(&self, variant: WKBVariant) -> Vec<u8>;
+    fn as_hexwkb(&self, variant: WKBVariant) -> String;
+
+    /// Consumes the box and returns its WKB representation.
+    ///
+    /// `as_wkb` already returns an owned buffer: the bytes are copied out of the MEOS buffer,
+    /// which is freed before returning. This is a convenience for when the box is no longer
+    /// needed, and drops it right away.
+    fn into_wkb(self, variant: WKBVariant) -> Vec<u8>
+    where
+        Self: Sized,
+    {
+        self.as_wkb(variant)
+    }
+
     fn round(&self, max_decimals: i32) -> Self;
     fn expand_time(&self, other: TimeDelta) -> Self;
     fn is_tmin_inclusive(&self) -> Option<bool>;
@@ -9,6 +9,16 @@ use chrono::{DateTime, TimeDelta, TimeZone, Utc};
 #[cfg(feature = "geos")]
 use geos::{Geom, Geometry};
 
+#[cfg(feature = "geos")]
+use crate::temporal::{
+    point::{
+        tgeompoint::{TGeomPoint, TGeomPointInstant},
+        tpoint::geometry_to_gserialized,
+    },
+    temporal::Temporal,
+    tinstant::TInstant,
+};
+
 use crate::{
     collections::{
         base::{
@@ -18,7 +28,8 @@ use crate::{
         datetime::tstz_span::TsTzSpan,
     },
     errors::ParseError,
-    utils::{create_interval, from_meos_timestamp, to_meos_timestamp},
+    impl_serde,
+    utils::{duration_to_interval, from_meos_timestamp, to_meos_timestamp},
     WKBVariant,
 };
 
@@ -72,20 +83,25 @@ impl MeosBox for STBox {
         unsafe { TsTzSpan::from_inner(meos_sys::stbox_to_tstzspan(self.inner())) }
     }
 
-    fn as_wkb(&self, variant: WKBVariant) -> &[u8] {
+    fn as_wkb(&self, variant: WKBVariant) -> Vec<u8> {
         unsafe {
             let mut size: usize = 0;
             let ptr = meos_sys::stbox_as_wkb(self.inner(), variant.into(), &mut size);
-            std::slice::from_raw_parts(ptr, size)
+            let bytes = std::slice::from_raw_parts(ptr, size).to_vec();
+            libc::free(ptr as *mut c_void);
+            bytes
         }
     }
 
-    fn as_hexwkb(&self, variant: WKBVariant) -> &[u8] {
+    fn as_hexwkb(&self, variant: WKBVariant) -> String {
         unsafe {
             let mut size: usize = 0;
             let hexwkb_ptr = meos_sys::stbox_as_hexwkb(self.inner(), variant.into(), &mut size);
+            let hexwkb = CStr::from_ptr(hexwkb_ptr).to_str().unwrap().to_owned();
+
+            libc::free(hexwkb_ptr as *mut c_void);
 
-            CStr::from_ptr(hexwkb_ptr).to_bytes()
+            hexwkb
         }
     }
 
@@ -174,7 +190,7 @@ impl MeosBox for STBox {
     // ------------------------- Transformation --------------------------------
 
     fn expand_time(&self, duration: TimeDelta) -> STBox {
-        let interval = create_interval(duration);
+        let interval = duration_to_interval(duration);
         unsafe {
             Self::from_inner(meos_sys::stbox_expand_time(
                 self.inner(),
@@ -186,7 +202,7 @@ impl MeosBox for STBox {
     fn shift_scale_time(&self, delta: Option<TimeDelta>, width: Option<TimeDelta>) -> STBox {
         let d = {
             if let Some(d) = delta {
-                &*Box::new(create_interval(d)) as *const meos_sys::Interval
+                &*Box::new(duration_to_interval(d)) as *const meos_sys::Interval
             } else {
                 std::ptr::null()
             }
@@ -194,7 +210,7 @@ impl MeosBox for STBox {
 
         let w = {
             if let Some(w) = width {
-                &*Box::new(create_interval(w)) as *const meos_sys::Interval
+                &*Box::new(duration_to_interval(w)) as *const meos_sys::Interval
             } else {
                 std::ptr::null()
             }
@@ -263,7 +279,30 @@ impl STBox {
     #[cfg(feature = "geos")]
     pub fn geos_geometry(&self) -> Option<Geometry> {
         // meos_sys::geo_as_ewkb(meos_sys::stbox_to_geo(box_))
-        Geometry::new_from_wkb(self.as_wkb(WKBVariant::none())).ok()
+        Geometry::new_from_wkb(&self.as_wkb(WKBVariant::none())).ok()
+    }
+
+    /// Creates a box centered on the position of `center`, extending `radius` in every spatial
+    /// direction and spanning the time window `time`. Useful as a geofence for `at_stbox`.
+    ///
+    /// ## Arguments
+    /// * `center` - The instant whose position is the center of the box.
+    /// * `radius` - The distance to expand the position by, in the units of its SRID.
+    /// * `time` - The time extent of the box.
+    ///
+    /// ## Returns
+    /// A new `STBox`.
+    ///
+    /// MEOS Functions:
+    ///     `geo_tstzspan_to_stbox`, `stbox_expand_space`
+    #[cfg(feature = "geos")]
+    pub fn around(center: &TGeomPointInstant, radius: f64, time: TsTzSpan) -> STBox {
+        let position = geometry_to_gserialized(&center.value());
+        let point_box = unsafe { meos_sys::geo_tstzspan_to_stbox(position, time.inner()) };
+        unsafe { libc::free(position as *mut c_void) };
+        let result = unsafe { meos_sys::stbox_expand_space(point_box, radius) };
+        unsafe { libc::free(point_box as *mut c_void) };
+        Self::from_inner(result)
     }
 
     // ------------------------- Transformation --------------------------------
@@ -271,6 +310,25 @@ impl STBox {
     pub fn expand_space(&self, value: f64) -> STBox {
         unsafe { Self::from_inner(meos_sys::stbox_expand_space(self.inner(), value)) }
     }
+
+    // ------------------------- Set Operations --------------------------------
+
+    /// Computes the union of the bounding boxes of all the given temporal points.
+    ///
+    /// ## Arguments
+    /// * `points` - A slice of `TGeomPoint` instances.
+    ///
+    /// ## Returns
+    /// An `Option<STBox>` enclosing every point, or `None` if `points` is empty.
+    ///
+    /// MEOS Functions:
+    ///     `tpoint_to_stbox`, `union_stbox_stbox`
+    #[cfg(feature = "geos")]
+    pub fn union_all(points: &[TGeomPoint]) -> Option<STBox> {
+        let mut boxes = points.iter().map(|point| point.bounding_box());
+        let first = boxes.next()?;
+        boxes.try_fold(first, |acc, bbox| acc.union(&bbox, false))
+    }
 }
 
 impl Collection for STBox {
@@ -307,12 +365,19 @@ impl cmp::PartialEq for STBox {
 
 impl cmp::Eq for STBox {}
 
+impl_serde!(STBox, |stbox| unsafe {
+    meos_sys::stbox_out(stbox.inner(), 15)
+});
+
 impl Debug for STBox {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let out_str = unsafe { meos_sys::stbox_out(self.inner(), 3) };
         let c_str = unsafe { CStr::from_ptr(out_str) };
-        let str = c_str.to_str().map_err(|_| std::fmt::Error)?;
-        let result = f.write_str(str);
+        // Write before freeing `out_str`, as `c_str` borrows it
+        let result = c_str
+            .to_str()
+            .map_err(|_| std::fmt::Error)
+            .and_then(|str| f.write_str(str));
         unsafe { libc::free(out_str as *mut c_void) };
         result
     }
@@ -324,6 +389,14 @@ impl Clone for STBox {
     }
 }
 
+impl Drop for STBox {
+    fn drop(&mut self) {
+        unsafe {
+            libc::free(self._inner.as_ptr() as *mut c_void);
+        }
+    }
+}
+
 impl std::str::FromStr for STBox {
     type Err = ParseError;
     /// Parses a `STBox` from a string representation.
@@ -366,3 +439,100 @@ impl From<&STBox> for TsTzSpan {
         unsafe { TsTzSpan::from_inner(meos_sys::stbox_to_tstzspan(stbox.inner())) }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::meos_initialize;
+    use std::str::FromStr;
+
+    #[test]
+    #[cfg(feature = "geos")]
+    #[serial_test::serial]
+    fn union_all_encloses_every_trajectory() {
+        meos_initialize("UTC");
+        let trajectories: Vec<TGeomPoint> = [
+            "[POINT(0 0)@2020-01-01 00:00:00+00, POINT(1 1)@2020-01-01 01:00:00+00]",
+            "[POINT(5 2)@2020-01-02 00:00:00+00, POINT(6 3)@2020-01-02 01:00:00+00]",
+            "[POINT(-2 8)@2020-01-03 00:00:00+00, POINT(-1 9)@2020-01-03 01:00:00+00]",
+        ]
+        .iter()
+        .map(|string| TGeomPoint::from_str(string).unwrap())
+        .collect();
+
+        let union = STBox::union_all(&trajectories).unwrap();
+        for trajectory in &trajectories {
+            assert!(trajectory.bounding_box().is_contained_in(&union));
+        }
+        assert_eq!(
+            union,
+            STBox::from_str("STBOX XT(((-2,0),(6,9)),[2020-01-01, 2020-01-03 01:00:00])").unwrap()
+        );
+        assert!(STBox::union_all(&[]).is_none());
+    }
+
+    #[test]
+    #[cfg(feature = "geos")]
+    #[serial_test::serial]
+    fn around_builds_geofence() {
+        meos_initialize("UTC");
+        let center = TGeomPointInstant::from_value_and_timestamp(
+            Geometry::new_from_wkt("POINT(1 2)").unwrap(),
+            Utc.with_ymd_and_hms(2020, 1, 1, 12, 0, 0).unwrap(),
+        );
+        let time = TsTzSpan::from_str("[2020-01-01 11:00:00+00, 2020-01-01 13:00:00+00]").unwrap();
+        let window = STBox::around(&center, 5.0, time.clone());
+
+        assert!(center.bounding_box().is_contained_in(&window));
+        assert_eq!(window.xmin(), Some(-4.0));
+        assert_eq!(window.xmax(), Some(6.0));
+        assert_eq!(TsTzSpan::from(&window), time);
+    }
+
+    #[test]
+    fn clone_and_drop_stbox() {
+        meos_initialize("UTC");
+        let original = STBox::from_str("STBOX XT(((1,2),(3,4)),[2020-01-01, 2020-01-02])").unwrap();
+        for _ in 0..1000 {
+            let copy = original.clone();
+            let copy_of_copy = copy.clone();
+            drop(copy);
+            assert_eq!(copy_of_copy, original);
+        }
+    }
+
+    #[test]
+    fn debug_and_wkb_stbox() {
+        meos_initialize("UTC");
+        let original = STBox::from_str("STBOX XT(((1,2),(3,4)),[2020-01-01, 2020-01-02])").unwrap();
+        let text = format!("{original:?}");
+        let wkb = original.as_wkb(WKBVariant::none());
+        for _ in 0..10_000 {
+            assert_eq!(format!("{original:?}"), text);
+            let round_trip = STBox::from_wkb(&original.as_wkb(WKBVariant::none()));
+            assert_eq!(round_trip, original);
+            assert_eq!(round_trip.into_wkb(WKBVariant::none()), wkb);
+        }
+    }
+
+    #[test]
+    fn contains_smaller_stbox() {
+        meos_initialize("UTC");
+        let big = STBox::from_str("STBOX XT(((0,0),(10,10)),[2020-01-01, 2020-01-10])").unwrap();
+        let small = STBox::from_str("STBOX XT(((2,2),(3,3)),[2020-01-02, 2020-01-03])").unwrap();
+        assert!(big.contains(&small));
+        assert!(small.is_contained_in(&big));
+        assert!(!small.contains(&big));
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn serde_roundtrip_stbox() {
+        meos_initialize("UTC");
+        let original =
+            STBox::from_str("STBOX XT(((1.123456789,2),(3,4)),[2020-01-01, 2020-01-02])").unwrap();
+        let json = serde_json::to_string(&original).unwrap();
+        assert_eq!(serde_json::from_str::<STBox>(&json).unwrap(), original);
+        assert!(serde_json::from_str::<STBox>("\"STBOX\"").is_err());
+    }
+}
@@ -18,8 +18,9 @@ use crate::{
         number::{float_span::FloatSpan, int_span::IntSpan, number_span::NumberSpan},
     },
     errors::ParseError,
-    temporal::number::tfloat::TFloat,
-    utils::{create_interval, from_meos_timestamp, to_meos_timestamp},
+    impl_serde,
+    temporal::number::{tfloat::TFloat, tnumber::TNumber},
+    utils::{duration_to_interval, from_meos_timestamp, to_meos_timestamp},
     WKBVariant,
 };
 
@@ -91,19 +92,23 @@ impl MeosBox for TBox {
         unsafe { TsTzSpan::from_inner(meos_sys::tbox_to_tstzspan(self.inner())) }
     }
 
-    fn as_wkb(&self, variant: WKBVariant) -> &[u8] {
+    fn as_wkb(&self, variant: WKBVariant) -> Vec<u8> {
         unsafe {
             let mut size: usize = 0;
             let ptr = meos_sys::tbox_as_wkb(self.inner(), variant.into(), &mut size);
-            std::slice::from_raw_parts(ptr, size)
+            let bytes = std::slice::from_raw_parts(ptr, size).to_vec();
+            libc::free(ptr as *mut c_void);
+            bytes
         }
     }
 
-    fn as_hexwkb(&self, variant: WKBVariant) -> &[u8] {
+    fn as_hexwkb(&self, variant: WKBVariant) -> String {
         unsafe {
             let mut size: usize = 0;
             let ptr = meos_sys::tbox_as_hexwkb(self.inner(), variant.into(), &mut size);
-            CStr::from_ptr(ptr).to_bytes()
+            let hexwkb = CStr::from_ptr(ptr).to_str().unwrap().to_owned();
+            libc::free(ptr as *mut c_void);
+            hexwkb
         }
     }
 
@@ -311,7 +316,7 @@ impl MeosBox for TBox {
     /// assert!(expanded_tbox.tmax().unwrap() > datetime);
     /// ```
     fn expand_time(&self, duration: TimeDelta) -> TBox {
-        let interval = create_interval(duration);
+        let interval = duration_to_interval(duration);
         unsafe {
             Self::from_inner(meos_sys::tbox_expand_time(
                 self.inner(),
@@ -382,7 +387,7 @@ impl MeosBox for TBox {
     /// let datetime2 = Utc.with_ymd_and_hms(2020, 2, 1, 0, 0, 0).unwrap();
     /// let tspan: TsTzSpan = (datetime1..datetime2).into();
     /// let tbox = TBox::from_temporal_span(tspan);
-    /// let shifted_scaled_tbox = tbox.shift_scale_time(Some(TimeDelta::days(1)), Some(TimeDelta::days(2)));
+    /// let shifted_scaled_tbox = tbox.shift_scale_time(Some(TimeDelta::days(2)), Some(TimeDelta::days(4)));
     ///
     /// assert_eq!(shifted_scaled_tbox.tmin().unwrap(), datetime1 + TimeDelta::days(2));
     /// assert_eq!(shifted_scaled_tbox.tmax().unwrap(), Utc.with_ymd_and_hms(2020, 1, 7, 0, 0, 0).unwrap());
@@ -390,7 +395,7 @@ impl MeosBox for TBox {
     fn shift_scale_time(&self, delta: Option<TimeDelta>, width: Option<TimeDelta>) -> TBox {
         let d = {
             if let Some(d) = delta {
-                &*Box::new(create_interval(d)) as *const meos_sys::Interval
+                &*Box::new(duration_to_interval(d)) as *const meos_sys::Interval
             } else {
                 std::ptr::null()
             }
@@ -398,7 +403,7 @@ impl MeosBox for TBox {
 
         let w = {
             if let Some(w) = width {
-                &*Box::new(create_interval(w)) as *const meos_sys::Interval
+                &*Box::new(duration_to_interval(w)) as *const meos_sys::Interval
             } else {
                 std::ptr::null()
             }
@@ -650,6 +655,24 @@ impl TBox {
         };
         TBox::from_inner(modified)
     }
+
+    // ------------------------- Set Operations --------------------------------
+
+    /// Computes the union of the bounding boxes of all the given temporal numbers.
+    ///
+    /// ## Arguments
+    /// * `numbers` - A slice of temporal numbers.
+    ///
+    /// ## Returns
+    /// An `Option<TBox>` enclosing every number, or `None` if `numbers` is empty.
+    ///
+    /// MEOS Functions:
+    ///     `tnumber_to_tbox`, `union_tbox_tbox`
+    pub fn union_all(numbers: &[impl TNumber]) -> Option<TBox> {
+        let mut boxes = numbers.iter().map(|number| TNumber::bounding_box(number));
+        let first = boxes.next()?;
+        boxes.try_fold(first, |acc, bbox| acc.union(&bbox, false))
+    }
 }
 
 impl Collection for TBox {
@@ -687,12 +710,17 @@ impl cmp::PartialEq for TBox {
 
 impl cmp::Eq for TBox {}
 
+impl_serde!(TBox, |tbox| unsafe { meos_sys::tbox_out(tbox.inner(), 15) });
+
 impl Debug for TBox {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let out_str = unsafe { meos_sys::tbox_out(self.inner(), 3) };
         let c_str = unsafe { CStr::from_ptr(out_str) };
-        let str = c_str.to_str().map_err(|_| std::fmt::Error)?;
-        let result = f.write_str(str);
+        // Write before freeing `out_str`, as `c_str` borrows it
+        let result = c_str
+            .to_str()
+            .map_err(|_| std::fmt::Error)
+            .and_then(|str| f.write_str(str));
         unsafe { libc::free(out_str as *mut c_void) };
         result
     }
@@ -704,6 +732,14 @@ impl Clone for TBox {
     }
 }
 
+impl Drop for TBox {
+    fn drop(&mut self) {
+        unsafe {
+            libc::free(self._inner.as_ptr() as *mut c_void);
+        }
+    }
+}
+
 impl std::str::FromStr for TBox {
     type Err = ParseError;
     /// Parses a `TBox` from a string representation.
@@ -741,3 +777,74 @@ impl std::str::FromStr for TBox {
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::meos_initialize;
+    use std::str::FromStr;
+
+    #[test]
+    fn union_all_encloses_every_number() {
+        meos_initialize("UTC");
+        let numbers: Vec<TFloat> = [
+            "[1.5@2020-01-01 00:00:00+00, 3.5@2020-01-01 01:00:00+00]",
+            "[-2@2020-01-02 00:00:00+00, 0@2020-01-02 01:00:00+00]",
+            "[10@2020-01-03 00:00:00+00, 12@2020-01-03 01:00:00+00]",
+        ]
+        .iter()
+        .map(|string| TFloat::from_str(string).unwrap())
+        .collect();
+
+        let union = TBox::union_all(&numbers).unwrap();
+        for number in &numbers {
+            assert!(TNumber::bounding_box(number).is_contained_in(&union));
+        }
+        assert_eq!(
+            union,
+            TBox::from_str("TBOXFLOAT XT([-2, 12],[2020-01-01, 2020-01-03 01:00:00])").unwrap()
+        );
+        let empty: [TFloat; 0] = [];
+        assert!(TBox::union_all(&empty).is_none());
+    }
+
+    #[test]
+    fn clone_and_drop_tbox() {
+        meos_initialize("UTC");
+        let original = TBox::from_str("TBOXFLOAT XT([0, 10),[2020-06-01, 2020-06-05])").unwrap();
+        for _ in 0..1000 {
+            let copy = original.clone();
+            let copy_of_copy = copy.clone();
+            drop(copy);
+            assert_eq!(copy_of_copy, original);
+        }
+    }
+
+    #[test]
+    fn debug_and_wkb_tbox() {
+        meos_initialize("UTC");
+        let original = TBox::from_str("TBOXFLOAT XT([0, 10),[2020-06-01, 2020-06-05])").unwrap();
+        let text = format!("{original:?}");
+        let wkb = original.as_wkb(WKBVariant::none());
+        for _ in 0..10_000 {
+            assert_eq!(format!("{original:?}"), text);
+            let round_trip = TBox::from_wkb(&original.as_wkb(WKBVariant::none()));
+            assert_eq!(round_trip, original);
+            assert_eq!(round_trip.into_wkb(WKBVariant::none()), wkb);
+        }
+    }
+
+    #[test]
+    fn contains_tfloat() {
+        meos_initialize("UTC");
+        let tbox = TBox::from_str("TBOXFLOAT XT([0, 10],[2020-01-01, 2020-01-10])").unwrap();
+        let inside = TFloat::from_str("[1.5@2020-01-02, 2.5@2020-01-03]").unwrap();
+        let outside = TFloat::from_str("[1.5@2020-01-02, 12.5@2020-01-03]").unwrap();
+        assert!(tbox.contains(&inside));
+        assert!(!tbox.contains(&outside));
+
+        let small = TBox::from_str("TBOXFLOAT XT([2, 3],[2020-01-02, 2020-01-03])").unwrap();
+        assert!(small.is_contained_in(&tbox));
+        assert!(!tbox.is_contained_in(&small));
+    }
+}
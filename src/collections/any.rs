@@ -0,0 +1,109 @@
+use super::{
+    datetime::{date_span::DateSpan, tstz_span::TsTzSpan},
+    number::{float_span::FloatSpan, int_span::IntSpan},
+};
+
+/// The base type of an [`AnySpan`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SpanKind {
+    Int,
+    Float,
+    Date,
+    TsTz,
+}
+
+/// A span whose base type is only known at runtime, e.g. to store spans of different types in
+/// the same `Vec`.
+///
+/// ## Example
+/// ```
+/// # use meos::collections::any::{AnySpan, SpanKind};
+/// # use meos::collections::datetime::tstz_span::TsTzSpan;
+/// # use meos::collections::number::int_span::IntSpan;
+/// # use meos::meos_initialize;
+/// use std::str::FromStr;
+/// # meos_initialize("UTC");
+/// let spans: Vec<AnySpan> = vec![
+///     IntSpan::from(1..10).into(),
+///     TsTzSpan::from_str("[2020-01-01, 2020-01-02]").unwrap().into(),
+/// ];
+/// let kinds: Vec<SpanKind> = spans.iter().map(AnySpan::kind).collect();
+/// assert_eq!(kinds, vec![SpanKind::Int, SpanKind::TsTz]);
+/// assert_eq!(spans[0].as_int_span(), Some(&IntSpan::from(1..10)));
+/// assert!(spans[1].as_int_span().is_none());
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub enum AnySpan {
+    Int(IntSpan),
+    Float(FloatSpan),
+    Date(DateSpan),
+    TsTz(TsTzSpan),
+}
+
+impl AnySpan {
+    /// Returns the base type of the span.
+    pub fn kind(&self) -> SpanKind {
+        match self {
+            AnySpan::Int(_) => SpanKind::Int,
+            AnySpan::Float(_) => SpanKind::Float,
+            AnySpan::Date(_) => SpanKind::Date,
+            AnySpan::TsTz(_) => SpanKind::TsTz,
+        }
+    }
+
+    /// Returns the span if it is an `IntSpan`.
+    pub fn as_int_span(&self) -> Option<&IntSpan> {
+        match self {
+            AnySpan::Int(span) => Some(span),
+            _ => None,
+        }
+    }
+
+    /// Returns the span if it is a `FloatSpan`.
+    pub fn as_float_span(&self) -> Option<&FloatSpan> {
+        match self {
+            AnySpan::Float(span) => Some(span),
+            _ => None,
+        }
+    }
+
+    /// Returns the span if it is a `DateSpan`.
+    pub fn as_date_span(&self) -> Option<&DateSpan> {
+        match self {
+            AnySpan::Date(span) => Some(span),
+            _ => None,
+        }
+    }
+
+    /// Returns the span if it is a `TsTzSpan`.
+    pub fn as_tstz_span(&self) -> Option<&TsTzSpan> {
+        match self {
+            AnySpan::TsTz(span) => Some(span),
+            _ => None,
+        }
+    }
+}
+
+impl From<IntSpan> for AnySpan {
+    fn from(span: IntSpan) -> Self {
+        AnySpan::Int(span)
+    }
+}
+
+impl From<FloatSpan> for AnySpan {
+    fn from(span: FloatSpan) -> Self {
+        AnySpan::Float(span)
+    }
+}
+
+impl From<DateSpan> for AnySpan {
+    fn from(span: DateSpan) -> Self {
+        AnySpan::Date(span)
+    }
+}
+
+impl From<TsTzSpan> for AnySpan {
+    fn from(span: TsTzSpan) -> Self {
+        AnySpan::TsTz(span)
+    }
+}
@@ -1,4 +1,4 @@
-use std::ffi::{CStr, CString};
+use std::ffi::{c_void, CStr, CString};
 
 use crate::WKBVariant;
 
@@ -36,19 +36,40 @@ pub trait Span: Collection {
 
     fn from_inner(inner: *mut meos_sys::Span) -> Self;
 
-    fn as_wkb(&self, variant: WKBVariant) -> &[u8] {
+    /// Creates a new `Span` from its bounds.
+    ///
+    /// ## Arguments
+    /// * `lower` - The lower bound.
+    /// * `upper` - The upper bound.
+    /// * `lower_inclusive` - Whether the lower bound is inclusive.
+    /// * `upper_inclusive` - Whether the upper bound is inclusive.
+    ///
+    /// ## Returns
+    /// * A new `Span` instance.
+    fn from_bounds(
+        lower: Self::Type,
+        upper: Self::Type,
+        lower_inclusive: bool,
+        upper_inclusive: bool,
+    ) -> Self;
+
+    fn as_wkb(&self, variant: WKBVariant) -> Vec<u8> {
         unsafe {
             let mut size = 0;
             let wkb = meos_sys::span_as_wkb(self.inner(), variant.into(), &mut size as *mut _);
-            std::slice::from_raw_parts(wkb, size)
+            let bytes = std::slice::from_raw_parts(wkb, size).to_vec();
+            libc::free(wkb as *mut c_void);
+            bytes
         }
     }
 
-    fn as_hexwkb(&self, variant: WKBVariant) -> &[u8] {
+    fn as_hexwkb(&self, variant: WKBVariant) -> String {
         unsafe {
             let mut size: usize = 0;
             let hexwkb_ptr = meos_sys::span_as_hexwkb(self.inner(), variant.into(), &mut size);
-            CStr::from_ptr(hexwkb_ptr).to_bytes()
+            let hexwkb = CStr::from_ptr(hexwkb_ptr).to_str().unwrap().to_owned();
+            libc::free(hexwkb_ptr as *mut c_void);
+            hexwkb
         }
     }
 
@@ -100,6 +121,49 @@ pub trait Span: Collection {
         unsafe { meos_sys::span_upper_inc(self.inner()) }
     }
 
+    /// Return a new `Span` with the lower bound replaced by `lower`, keeping the upper bound unchanged.
+    ///
+    /// ## Arguments
+    /// * `lower` - The new lower bound.
+    /// * `inclusive` - Whether the new lower bound is inclusive.
+    ///
+    /// ## Example
+    /// ```
+    /// # use meos::collections::number::float_span::FloatSpan;
+    /// # use meos::collections::base::span::Span;
+    ///
+    /// let span: FloatSpan = (23.9..78.8).into();
+    /// let span = span.with_lower(10.0, false);
+    /// assert_eq!(span.lower(), 10.0);
+    /// assert!(!span.is_lower_inclusive());
+    /// assert_eq!(span.upper(), 78.8);
+    /// ```
+    fn with_lower(&self, lower: Self::Type, inclusive: bool) -> Self {
+        Self::from_bounds(lower, self.upper(), inclusive, self.is_upper_inclusive())
+    }
+
+    /// Return a new `Span` with the upper bound replaced by `upper`, keeping the lower bound unchanged.
+    ///
+    /// ## Arguments
+    /// * `upper` - The new upper bound.
+    /// * `inclusive` - Whether the new upper bound is inclusive.
+    ///
+    /// ## Example
+    /// ```
+    /// # use meos::collections::number::float_span::FloatSpan;
+    /// # use meos::collections::base::span::Span;
+    ///
+    /// let span: FloatSpan = (2.5..7.5).into();
+    /// let span = span.with_upper(10.0, true);
+    /// assert_eq!(span.lower(), 2.5);
+    /// assert!(span.is_lower_inclusive());
+    /// assert_eq!(span.upper(), 10.0);
+    /// assert!(span.is_upper_inclusive());
+    /// ```
+    fn with_upper(&self, upper: Self::Type, inclusive: bool) -> Self {
+        Self::from_bounds(self.lower(), upper, self.is_lower_inclusive(), inclusive)
+    }
+
     /// Return a new `Span` with the lower and upper bounds shifted by `delta`.
     fn shift(&self, delta: Self::SubsetType) -> Self;
 
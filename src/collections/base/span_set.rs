@@ -1,5 +1,5 @@
 use std::{
-    ffi::{CStr, CString},
+    ffi::{c_void, CStr, CString},
     ptr,
 };
 
@@ -45,21 +45,25 @@ pub trait SpanSet: Collection + FromIterator<Self::SpanType> {
 
     fn from_inner(inner: *mut meos_sys::SpanSet) -> Self;
 
-    fn as_wkb(&self, variant: WKBVariant) -> &[u8] {
+    fn as_wkb(&self, variant: WKBVariant) -> Vec<u8> {
         unsafe {
             let mut size = 0;
             let wkb =
                 meos_sys::spanset_as_wkb(self.inner(), variant.into(), ptr::addr_of_mut!(size));
-            std::slice::from_raw_parts(wkb, size)
+            let bytes = std::slice::from_raw_parts(wkb, size).to_vec();
+            libc::free(wkb as *mut c_void);
+            bytes
         }
     }
 
-    fn as_hexwkb(&self, variant: WKBVariant) -> &[u8] {
+    fn as_hexwkb(&self, variant: WKBVariant) -> String {
         unsafe {
             let mut size = 0;
             let wkb =
                 meos_sys::spanset_as_hexwkb(self.inner(), variant.into(), ptr::addr_of_mut!(size));
-            CStr::from_ptr(wkb).to_bytes()
+            let hexwkb = CStr::from_ptr(wkb).to_str().unwrap().to_owned();
+            libc::free(wkb as *mut c_void);
+            hexwkb
         }
     }
 
@@ -93,6 +97,71 @@ pub trait SpanSet: Collection + FromIterator<Self::SpanType> {
         }
     }
 
+    /// Returns the span of the set that contains `value`.
+    ///
+    /// ## Arguments
+    /// * `value` - The value to look for.
+    ///
+    /// ## Returns
+    /// * The span containing `value`, or `None` if no span contains it.
+    ///
+    /// ## Example
+    /// ```
+    /// # use meos::collections::number::int_span_set::IntSpanSet;
+    /// # use meos::collections::number::int_span::IntSpan;
+    /// # use meos::collections::base::span_set::SpanSet;
+    /// # use std::str::FromStr;
+    ///
+    /// let span_set = IntSpanSet::from_str("{[1, 3), [5, 8)}").unwrap();
+    /// let expected: IntSpan = (5..8).into();
+    /// assert_eq!(span_set.span_containing(&6), Some(expected));
+    /// assert_eq!(span_set.span_containing(&4), None);
+    /// ```
+    fn span_containing(&self, value: &Self::Type) -> Option<Self::SpanType>
+    where
+        Self::SpanType: Collection<Type = Self::Type>,
+    {
+        if !self.contains(value) {
+            return None;
+        }
+        // MEOS span indexes are 1-based
+        (1..=self.num_spans())
+            .map(|n| self.span_n(n))
+            .find(|span| span.contains(value))
+    }
+
+    /// Returns the lower bound of the span set, i.e., the lower bound of its first span,
+    /// without copying that span.
+    ///
+    /// ## Example
+    /// ```
+    /// # use meos::collections::number::int_span_set::IntSpanSet;
+    /// # use meos::collections::base::span_set::SpanSet;
+    /// # use meos::collections::base::span::Span;
+    /// # use std::str::FromStr;
+    ///
+    /// let span_set = IntSpanSet::from_str("{[1, 3), [5, 8)}").unwrap();
+    /// assert_eq!(span_set.lower_bound(), 1);
+    /// assert_eq!(span_set.lower_bound(), span_set.start_span().lower());
+    /// ```
+    fn lower_bound(&self) -> Self::Type;
+
+    /// Returns the upper bound of the span set, i.e., the upper bound of its last span,
+    /// without copying that span.
+    ///
+    /// ## Example
+    /// ```
+    /// # use meos::collections::number::int_span_set::IntSpanSet;
+    /// # use meos::collections::base::span_set::SpanSet;
+    /// # use meos::collections::base::span::Span;
+    /// # use std::str::FromStr;
+    ///
+    /// let span_set = IntSpanSet::from_str("{[1, 3), [5, 8)}").unwrap();
+    /// assert_eq!(span_set.upper_bound(), 8);
+    /// assert_eq!(span_set.upper_bound(), span_set.end_span().upper());
+    /// ```
+    fn upper_bound(&self) -> Self::Type;
+
     fn width(&self, ignore_gaps: bool) -> Self::Type;
 
     /// Return a new `SpanSet` with the lower and upper bounds shifted by `delta`.
@@ -14,7 +14,8 @@ use span::Span;
 use crate::{
     collections::{base::*, datetime::DAYS_UNTIL_2000},
     errors::ParseError,
-    utils::from_interval,
+    impl_serde,
+    utils::interval_to_duration,
 };
 
 pub struct DateSpan {
@@ -56,6 +57,29 @@ impl span::Span for DateSpan {
         }
     }
 
+    fn from_bounds(
+        lower: NaiveDate,
+        upper: NaiveDate,
+        lower_inclusive: bool,
+        upper_inclusive: bool,
+    ) -> Self {
+        let inner = unsafe {
+            meos_sys::datespan_make(
+                lower
+                    .checked_sub_days(DAYS_UNTIL_2000)
+                    .unwrap()
+                    .num_days_from_ce(),
+                upper
+                    .checked_sub_days(DAYS_UNTIL_2000)
+                    .unwrap()
+                    .num_days_from_ce(),
+                lower_inclusive,
+                upper_inclusive,
+            )
+        };
+        Self::from_inner(inner)
+    }
+
     /// Returns the lower bound of the span.
     ///
     /// ## Returns
@@ -204,13 +228,13 @@ impl span::Span for DateSpan {
         DateSpan::from_inner(modified)
     }
 
-    /// Calculates the distance between this `DateSpan` and a specific timestamp (`value`).
+    /// Calculates the distance between this `DateSpan` and a specific date (`value`).
     ///
     /// ## Arguments
-    /// * `value` - Anvalue `DateSpan` to calculate the distance to.
+    /// * `value` - A date to calculate the distance to.
     ///
     /// ## Returns
-    /// A `TimeDelta` representing the distance in seconds between the two spans.
+    /// A `TimeDelta` representing the distance in whole days between the span and the date.
     ///
     /// ## Example
     /// ```
@@ -218,12 +242,11 @@ impl span::Span for DateSpan {
     /// # use meos::meos_initialize;
     /// use std::str::FromStr;
     /// # use meos::collections::base::span::Span;
-    /// use chrono::TimeDelta;
+    /// use chrono::{NaiveDate, TimeDelta};
     /// # meos_initialize("UTC");
-    /// let span1 = DateSpan::from_str("[2019-09-08, 2019-09-10]").unwrap();
-    /// let span2 = DateSpan::from_str("[2019-09-12, 2019-09-14]").unwrap();
-    /// let distance = span1.distance_to_span(&span2);
-    /// assert_eq!(distance, TimeDelta::days(2));
+    /// let span = DateSpan::from_str("[2019-09-08, 2019-09-10]").unwrap();
+    /// let date = NaiveDate::from_ymd_opt(2019, 9, 13).unwrap();
+    /// assert_eq!(span.distance_to_value(&date), TimeDelta::days(3));
     /// ```
     fn distance_to_value(&self, other: &Self::Type) -> TimeDelta {
         unsafe {
@@ -272,7 +295,7 @@ impl span::Span for DateSpan {
 
 impl DateSpan {
     pub fn duration(&self) -> TimeDelta {
-        from_interval(unsafe { meos_sys::datespan_duration(self._inner.as_ptr()).read() })
+        interval_to_duration(unsafe { meos_sys::datespan_duration(self._inner.as_ptr()).read() })
     }
 }
 
@@ -395,6 +418,10 @@ impl From<RangeInclusive<NaiveDate>> for DateSpan {
     }
 }
 
+impl_serde!(DateSpan, |span| unsafe {
+    meos_sys::datespan_out(span.inner())
+});
+
 impl Debug for DateSpan {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let out_str = unsafe { meos_sys::datespan_out(self._inner.as_ptr()) };
@@ -3,7 +3,9 @@ use std::ptr;
 
 use chrono::Datelike;
 use chrono::NaiveDate;
+use chrono::NaiveTime;
 use chrono::TimeDelta;
+use chrono::TimeZone;
 use collection::{impl_collection, Collection};
 use span::Span;
 use span_set::impl_iterator;
@@ -13,9 +15,12 @@ use std::ops::{BitAnd, BitOr};
 
 use crate::collections::base::span_set::SpanSet;
 use crate::collections::base::*;
-use crate::errors::ParseError;
+use crate::errors::{MeosError, ParseError};
+use crate::impl_serde;
 
 use super::date_span::DateSpan;
+use super::tstz_span::TsTzSpan;
+use super::tstz_span_set::TsTzSpanSet;
 use super::DAYS_UNTIL_2000;
 
 pub struct DateSpanSet {
@@ -54,6 +59,24 @@ impl span_set::SpanSet for DateSpanSet {
         unimplemented!("Not implemented for date")
     }
 
+    fn lower_bound(&self) -> Self::Type {
+        // The bounding span of the set shares the lower bound of its first span
+        let num_of_days = unsafe { meos_sys::datespan_lower(&(*self.inner()).span) };
+        NaiveDate::from_num_days_from_ce_opt(num_of_days)
+            .expect("Wrong date returned from meos")
+            .checked_add_days(DAYS_UNTIL_2000)
+            .unwrap()
+    }
+
+    fn upper_bound(&self) -> Self::Type {
+        // The bounding span of the set shares the upper bound of its last span
+        let num_of_days = unsafe { meos_sys::datespan_upper(&(*self.inner()).span) };
+        NaiveDate::from_num_days_from_ce_opt(num_of_days)
+            .expect("Wrong date returned from meos")
+            .checked_add_days(DAYS_UNTIL_2000)
+            .unwrap()
+    }
+
     /// Return a new `DateSpanSet` with the lower and upper bounds shifted by `delta`.
     ///
     /// ## Arguments
@@ -246,6 +269,81 @@ impl span_set::SpanSet for DateSpanSet {
     }
 }
 
+impl DateSpanSet {
+    /// Converts the dates of `self` into time windows starting at `time` on each day, e.g. to
+    /// schedule a recurring event.
+    ///
+    /// Each date span `[first, last]` becomes the window from `first` at `time` to the day after
+    /// `last` at `time`, exclusive, with `time` taken in the timezone `tz`. When `time` occurs
+    /// twice on a day, because the clocks are set back, the earliest one is used.
+    ///
+    /// ## Arguments
+    /// * `time` - The time of the day at which every window starts and ends.
+    /// * `tz` - The timezone of `time`, e.g. `Utc` or a `chrono_tz::Tz`.
+    ///
+    /// ## Returns
+    /// The windows, or an error if `time` is skipped on one of the bounding days, because the
+    /// clocks are set forward.
+    ///
+    /// ## Example
+    /// ```
+    /// # use meos::collections::base::span_set::SpanSet;
+    /// # use meos::collections::datetime::date_span_set::DateSpanSet;
+    /// # use meos::collections::datetime::tstz_span_set::TsTzSpanSet;
+    /// # use meos::meos_initialize;
+    /// use chrono::{NaiveTime, TimeZone, Utc};
+    /// use std::str::FromStr;
+    /// # meos_initialize("UTC");
+    /// let dates = DateSpanSet::from_str("{[2020-01-01, 2020-01-02]}").unwrap();
+    /// let eight = NaiveTime::from_hms_opt(8, 0, 0).unwrap();
+    ///
+    /// let windows = dates.to_tstz_span_set_at(eight, &Utc).unwrap();
+    /// assert_eq!(windows.lower_bound(), Utc.with_ymd_and_hms(2020, 1, 1, 8, 0, 0).unwrap());
+    /// assert_eq!(windows.upper_bound(), Utc.with_ymd_and_hms(2020, 1, 3, 8, 0, 0).unwrap());
+    ///
+    /// let windows = dates.to_tstz_span_set_at(eight, &chrono_tz::Europe::Brussels).unwrap();
+    /// assert_eq!(
+    ///     windows,
+    ///     TsTzSpanSet::from_str("{[2020-01-01 07:00:00+00, 2020-01-03 07:00:00+00)}").unwrap()
+    /// );
+    ///
+    /// let spring_forward = DateSpanSet::from_str("{[2020-03-29, 2020-03-30]}").unwrap();
+    /// let half_past_two = NaiveTime::from_hms_opt(2, 30, 0).unwrap();
+    /// assert!(spring_forward
+    ///     .to_tstz_span_set_at(half_past_two, &chrono_tz::Europe::Brussels)
+    ///     .is_err());
+    /// ```
+    ///
+    /// MEOS Functions:
+    ///     `tstzspan_make`, `spanset_make`
+    pub fn to_tstz_span_set_at<Tz: TimeZone>(
+        &self,
+        time: NaiveTime,
+        tz: &Tz,
+    ) -> Result<TsTzSpanSet, MeosError> {
+        let at = |date: NaiveDate| {
+            tz.from_local_datetime(&date.and_time(time))
+                .earliest()
+                .map(|timestamp| timestamp.to_utc())
+                .ok_or_else(|| MeosError {
+                    message: format!("{date} {time} does not exist in the timezone"),
+                })
+        };
+        // Date spans are canonicalized with an exclusive upper bound
+        self.spans()
+            .iter()
+            .map(|span| {
+                Ok(TsTzSpan::from_bounds(
+                    at(span.lower())?,
+                    at(span.upper())?,
+                    true,
+                    false,
+                ))
+            })
+            .collect()
+    }
+}
+
 impl Clone for DateSpanSet {
     fn clone(&self) -> DateSpanSet {
         self.copy()
@@ -279,6 +377,10 @@ impl std::cmp::PartialEq for DateSpanSet {
     }
 }
 
+impl_serde!(DateSpanSet, |span_set| unsafe {
+    meos_sys::datespanset_out(span_set.inner())
+});
+
 impl Debug for DateSpanSet {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let out_str = unsafe { meos_sys::datespanset_out(self.inner()) };
@@ -7,14 +7,19 @@ use std::{
     ptr,
 };
 
-use chrono::{DateTime, Datelike, TimeDelta, TimeZone, Utc};
+use chrono::{DateTime, NaiveDate, NaiveTime, TimeDelta, TimeZone, Utc};
 use collection::{impl_collection, Collection};
 use span::Span;
 
 use crate::{
     collections::base::*,
     errors::ParseError,
-    utils::{create_interval, from_interval, from_meos_timestamp, to_meos_timestamp},
+    impl_serde,
+    temporal::temporal::Temporal,
+    utils::{
+        duration_to_interval, format_timestamp, from_meos_timestamp, interval_to_duration,
+        to_meos_timestamp,
+    },
     BoundingBox,
 };
 
@@ -33,8 +38,22 @@ impl Drop for TsTzSpan {
 impl Collection for TsTzSpan {
     impl_collection!(span, DateTime<Utc>);
 
+    /// Returns whether `content` is within the span.
+    ///
+    /// ## Example
+    /// ```
+    /// # use meos::collections::base::collection::Collection;
+    /// # use meos::collections::datetime::tstz_span::TsTzSpan;
+    /// # use meos::meos_initialize;
+    /// use chrono::{TimeZone, Utc};
+    /// use std::str::FromStr;
+    /// # meos_initialize("UTC");
+    /// let hour = TsTzSpan::from_str("[2020-01-01 08:00:00+00, 2020-01-01 09:00:00+00]").unwrap();
+    /// assert!(hour.contains(&Utc.with_ymd_and_hms(2020, 1, 1, 8, 30, 0).unwrap()));
+    /// assert!(!hour.contains(&Utc.with_ymd_and_hms(2020, 1, 1, 10, 30, 0).unwrap()));
+    /// ```
     fn contains(&self, content: &DateTime<Utc>) -> bool {
-        unsafe { meos_sys::contains_span_date(self.inner(), content.num_days_from_ce()) }
+        unsafe { meos_sys::contains_span_timestamptz(self.inner(), to_meos_timestamp(content)) }
     }
 }
 
@@ -57,6 +76,23 @@ impl span::Span for TsTzSpan {
         }
     }
 
+    fn from_bounds(
+        lower: DateTime<Utc>,
+        upper: DateTime<Utc>,
+        lower_inclusive: bool,
+        upper_inclusive: bool,
+    ) -> Self {
+        let inner = unsafe {
+            meos_sys::tstzspan_make(
+                to_meos_timestamp(&lower),
+                to_meos_timestamp(&upper),
+                lower_inclusive,
+                upper_inclusive,
+            )
+        };
+        Self::from_inner(inner)
+    }
+
     /// Returns the lower bound of the span.
     ///
     /// ## Returns
@@ -127,7 +163,7 @@ impl span::Span for TsTzSpan {
     ///                                 .unwrap().and_utc();
     ///
     /// let span: TsTzSpan = (from_ymd_opt(2023, 1, 1)..from_ymd_opt(2023, 1, 15)).into();
-    /// let shifted_span = span.shift(TimeDelta::weeks(8));
+    /// let shifted_span = span.shift(TimeDelta::weeks(16));
     /// let expected_span: TsTzSpan = (from_ymd_opt(2023, 4, 23)..from_ymd_opt(2023, 5, 7)).into();
     /// assert_eq!(shifted_span, expected_span);
     /// ```
@@ -157,7 +193,7 @@ impl span::Span for TsTzSpan {
     ///                                 .unwrap().and_utc();
     ///
     /// let span: TsTzSpan = (from_ymd_opt(2023, 1, 1)..from_ymd_opt(2023, 1, 15)).into();
-    /// let scaled_span = span.scale(TimeDelta::weeks(4));
+    /// let scaled_span = span.scale(TimeDelta::weeks(8));
     /// let expected_span: TsTzSpan = (from_ymd_opt(2023, 1, 1)..from_ymd_opt(2023, 2, 26)).into();
     /// assert_eq!(scaled_span, expected_span);
     /// ```
@@ -187,14 +223,14 @@ impl span::Span for TsTzSpan {
     ///                                 .unwrap().and_utc();
     ///
     /// let span: TsTzSpan = (from_ymd_opt(2023, 1, 1)..from_ymd_opt(2023, 1, 15)).into();
-    /// let shifted_scaled_span = span.shift_scale(Some(TimeDelta::weeks(4)), Some(TimeDelta::weeks(4)));
+    /// let shifted_scaled_span = span.shift_scale(Some(TimeDelta::weeks(8)), Some(TimeDelta::weeks(8)));
     /// let expected_span: TsTzSpan = (from_ymd_opt(2023, 2, 26)..from_ymd_opt(2023, 4, 23)).into();
     /// assert_eq!(shifted_scaled_span, expected_span);
     /// ```
     fn shift_scale(&self, delta: Option<TimeDelta>, width: Option<TimeDelta>) -> TsTzSpan {
         let d = {
             if let Some(d) = delta {
-                &*Box::new(create_interval(d)) as *const meos_sys::Interval
+                &*Box::new(duration_to_interval(d)) as *const meos_sys::Interval
             } else {
                 std::ptr::null()
             }
@@ -202,7 +238,7 @@ impl span::Span for TsTzSpan {
 
         let w = {
             if let Some(w) = width {
-                &*Box::new(create_interval(w)) as *const meos_sys::Interval
+                &*Box::new(duration_to_interval(w)) as *const meos_sys::Interval
             } else {
                 std::ptr::null()
             }
@@ -214,10 +250,11 @@ impl span::Span for TsTzSpan {
     /// Calculates the distance between this `TsTzSpan` and a specific timestamp (`value`).
     ///
     /// ## Arguments
-    /// * `value` - Anvalue `TsTzSpan` to calculate the distance to.
+    /// * `value` - A timestamp to calculate the distance to.
     ///
     /// ## Returns
-    /// A `TimeDelta` representing the distance in seconds between the two spans.
+    /// A `TimeDelta` representing the distance between the span and the timestamp,
+    /// with a precision of seconds.
     ///
     /// ## Example
     /// ```
@@ -225,12 +262,11 @@ impl span::Span for TsTzSpan {
     /// # use meos::meos_initialize;
     /// use std::str::FromStr;
     /// # use meos::collections::base::span::Span;
-    /// use chrono::TimeDelta;
+    /// use chrono::{TimeDelta, TimeZone, Utc};
     /// # meos_initialize("UTC");
-    /// let span1 = TsTzSpan::from_str("[2019-09-08 00:00:00+00, 2019-09-10 00:00:00+00]").unwrap();
-    /// let span2 = TsTzSpan::from_str("[2019-09-12 00:00:00+00, 2019-09-14 00:00:00+00]").unwrap();
-    /// let distance = span1.distance_to_span(&span2);
-    /// assert_eq!(distance, TimeDelta::days(2));
+    /// let span = TsTzSpan::from_str("[2019-09-08 00:00:00+00, 2019-09-10 00:00:00+00]").unwrap();
+    /// let timestamp = Utc.with_ymd_and_hms(2019, 9, 13, 0, 0, 0).unwrap();
+    /// assert_eq!(span.distance_to_value(&timestamp), TimeDelta::days(3));
     /// ```
     fn distance_to_value(&self, value: &Self::Type) -> TimeDelta {
         unsafe {
@@ -272,7 +308,176 @@ impl span::Span for TsTzSpan {
 
 impl TsTzSpan {
     pub fn duration(&self) -> TimeDelta {
-        from_interval(unsafe { meos_sys::tstzspan_duration(self.inner()).read() })
+        interval_to_duration(unsafe { meos_sys::tstzspan_duration(self.inner()).read() })
+    }
+
+    /// Returns the textual representation of the span with its bounds in the timezone `tz`,
+    /// instead of the one MEOS was initialized with.
+    ///
+    /// ## Arguments
+    /// * `tz` - The timezone, e.g. `Utc` or a `chrono_tz::Tz`.
+    ///
+    /// ## Example
+    /// ```
+    /// # use meos::collections::datetime::tstz_span::TsTzSpan;
+    /// # use meos::meos_initialize;
+    /// use chrono::Utc;
+    /// use std::str::FromStr;
+    /// # meos_initialize("UTC");
+    /// let span = TsTzSpan::from_str("[2019-07-08 10:00:00+00, 2019-07-10 10:00:00+00]").unwrap();
+    /// assert_eq!(span.to_string_in_tz(&Utc), "[2019-07-08 10:00:00+00, 2019-07-10 10:00:00+00]");
+    /// assert_eq!(span.to_string_in_tz(&chrono_tz::Europe::Paris), "[2019-07-08 12:00:00+02, 2019-07-10 12:00:00+02]");
+    /// ```
+    pub fn to_string_in_tz<Tz: TimeZone>(&self, tz: &Tz) -> String {
+        format!(
+            "{}{}, {}{}",
+            if self.is_lower_inclusive() { '[' } else { '(' },
+            format_timestamp(&self.lower(), tz),
+            format_timestamp(&self.upper(), tz),
+            if self.is_upper_inclusive() { ']' } else { ')' },
+        )
+    }
+
+    /// Creates the span covering the whole calendar day `date` in UTC, from its midnight
+    /// (inclusive) to the next one (exclusive).
+    ///
+    /// ## Arguments
+    /// * `date` - The day to cover.
+    ///
+    /// ## Example
+    /// ```
+    /// # use meos::collections::datetime::tstz_span::TsTzSpan;
+    /// # use meos::meos_initialize;
+    /// use chrono::{NaiveDate, TimeDelta};
+    /// use std::str::FromStr;
+    /// # meos_initialize("UTC");
+    /// let leap_day = TsTzSpan::day(NaiveDate::from_ymd_opt(2020, 2, 29).unwrap());
+    /// assert_eq!(leap_day.duration(), TimeDelta::hours(24));
+    /// assert_eq!(leap_day, TsTzSpan::from_str("[2020-02-29 00:00:00+00, 2020-03-01 00:00:00+00)").unwrap());
+    /// ```
+    pub fn day(date: NaiveDate) -> TsTzSpan {
+        let start = date.and_time(NaiveTime::MIN).and_utc();
+        (start..start + TimeDelta::days(1)).into()
+    }
+
+    /// Creates the span covering the whole calendar month `month` of `year` in UTC, from the
+    /// midnight of its first day (inclusive) to the midnight of the next month (exclusive).
+    ///
+    /// ## Arguments
+    /// * `year` - The year of the month.
+    /// * `month` - The month, from 1 to 12.
+    ///
+    /// ## Panics
+    /// If `month` is not between 1 and 12.
+    ///
+    /// ## Example
+    /// ```
+    /// # use meos::collections::datetime::tstz_span::TsTzSpan;
+    /// # use meos::meos_initialize;
+    /// use std::str::FromStr;
+    /// # meos_initialize("UTC");
+    /// let february = TsTzSpan::month(2020, 2);
+    /// assert_eq!(february, TsTzSpan::from_str("[2020-02-01 00:00:00+00, 2020-03-01 00:00:00+00)").unwrap());
+    /// let december = TsTzSpan::month(2020, 12);
+    /// assert_eq!(december, TsTzSpan::from_str("[2020-12-01 00:00:00+00, 2021-01-01 00:00:00+00)").unwrap());
+    /// ```
+    pub fn month(year: i32, month: u32) -> TsTzSpan {
+        let first_day = |year, month| {
+            NaiveDate::from_ymd_opt(year, month, 1)
+                .expect("Month must be between 1 and 12")
+                .and_time(NaiveTime::MIN)
+                .and_utc()
+        };
+        let (next_year, next_month) = if month == 12 {
+            (year + 1, 1)
+        } else {
+            (year, month + 1)
+        };
+        (first_day(year, month)..first_day(next_year, next_month)).into()
+    }
+
+    /// Returns the smallest span covering the time of all of `items`, e.g. to compute a shared
+    /// time axis.
+    ///
+    /// ## Arguments
+    /// * `items` - The temporal objects to cover.
+    ///
+    /// ## Returns
+    /// The span from the earliest start to the latest end of `items`, or `None` if `items` is empty.
+    ///
+    /// ## Example
+    /// ```
+    /// # use meos::collections::base::collection::Collection;
+    /// # use meos::collections::datetime::tstz_span::TsTzSpan;
+    /// # use meos::temporal::number::tint::TInt;
+    /// # use meos::temporal::temporal::Temporal;
+    /// # use meos::meos_initialize;
+    /// use std::str::FromStr;
+    /// # meos_initialize("UTC");
+    /// let sequences = [
+    ///     TInt::from_str("[1@2020-01-02, 2@2020-01-04]").unwrap(),
+    ///     TInt::from_str("(1@2020-01-01, 2@2020-01-03]").unwrap(),
+    ///     TInt::from_str("[1@2020-01-03, 2@2020-01-05)").unwrap(),
+    /// ];
+    /// let enclosing = TsTzSpan::enclosing(&sequences).unwrap();
+    /// assert_eq!(enclosing, TsTzSpan::from_str("(2020-01-01, 2020-01-05)").unwrap());
+    /// assert!(sequences
+    ///     .iter()
+    ///     .all(|sequence| sequence.timespan().is_contained_in(&enclosing)));
+    /// assert_eq!(TsTzSpan::enclosing::<TInt>(&[]), None);
+    /// ```
+    pub fn enclosing<T: Temporal>(items: &[T]) -> Option<TsTzSpan> {
+        items
+            .iter()
+            .map(Temporal::timespan)
+            .reduce(|enclosing, span| {
+                let enclosing = if (span.lower(), !span.is_lower_inclusive())
+                    < (enclosing.lower(), !enclosing.is_lower_inclusive())
+                {
+                    enclosing.with_lower(span.lower(), span.is_lower_inclusive())
+                } else {
+                    enclosing
+                };
+                if (span.upper(), span.is_upper_inclusive())
+                    > (enclosing.upper(), enclosing.is_upper_inclusive())
+                {
+                    enclosing.with_upper(span.upper(), span.is_upper_inclusive())
+                } else {
+                    enclosing
+                }
+            })
+    }
+
+    /// Calculates the distance between this `TsTzSpan` and `timestamp`.
+    ///
+    /// Same as `distance_to_value`, but accepts a timestamp in any time zone.
+    ///
+    /// ## Arguments
+    /// * `timestamp` - A timestamp to calculate the distance to.
+    ///
+    /// ## Returns
+    /// A `TimeDelta` representing the distance between the span and the timestamp,
+    /// with a precision of seconds.
+    ///
+    /// ## Example
+    /// ```
+    /// # use meos::collections::datetime::tstz_span::TsTzSpan;
+    /// # use meos::meos_initialize;
+    /// use std::str::FromStr;
+    /// use chrono::{FixedOffset, TimeDelta, TimeZone};
+    /// # meos_initialize("UTC");
+    /// let span = TsTzSpan::from_str("[2019-09-08 00:00:00+00, 2019-09-10 00:00:00+00]").unwrap();
+    /// let timestamp = FixedOffset::east_opt(3600)
+    ///     .unwrap()
+    ///     .with_ymd_and_hms(2019, 9, 13, 1, 0, 0)
+    ///     .unwrap();
+    /// assert_eq!(span.distance_to_timestamp(&timestamp), TimeDelta::days(3));
+    /// ```
+    ///
+    /// MEOS Functions:
+    ///     `distance_span_timestamptz`
+    pub fn distance_to_timestamp<Tz: TimeZone>(&self, timestamp: &DateTime<Tz>) -> TimeDelta {
+        self.distance_to_value(&timestamp.with_timezone(&Utc))
     }
 }
 
@@ -388,6 +593,10 @@ impl<Tz: TimeZone> From<RangeInclusive<DateTime<Tz>>> for TsTzSpan {
     }
 }
 
+impl_serde!(TsTzSpan, |span| unsafe {
+    meos_sys::tstzspan_out(span.inner())
+});
+
 impl Debug for TsTzSpan {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let out_str = unsafe { meos_sys::tstzspan_out(self.inner()) };
@@ -2,8 +2,8 @@ use std::ffi::{c_void, CStr, CString};
 use std::ptr;
 
 use chrono::DateTime;
-use chrono::Datelike;
 use chrono::TimeDelta;
+use chrono::TimeZone;
 use chrono::Utc;
 use collection::{impl_collection, Collection};
 use span::Span;
@@ -15,10 +15,11 @@ use std::ops::{BitAnd, BitOr};
 use crate::collections::base::span_set::SpanSet;
 use crate::collections::base::*;
 use crate::errors::ParseError;
-use crate::utils::to_meos_timestamp;
+use crate::impl_serde;
+use crate::utils::{from_meos_timestamp, to_meos_timestamp};
 
 use super::tstz_span::TsTzSpan;
-use crate::utils::create_interval;
+use crate::utils::{duration_to_interval, interval_to_duration};
 
 pub struct TsTzSpanSet {
     _inner: ptr::NonNull<meos_sys::SpanSet>,
@@ -34,8 +35,23 @@ impl Drop for TsTzSpanSet {
 
 impl Collection for TsTzSpanSet {
     impl_collection!(spanset, DateTime<Utc>);
+    /// Returns whether `content` is within one of the spans of the span set.
+    ///
+    /// ## Example
+    /// ```
+    /// # use meos::collections::base::collection::Collection;
+    /// # use meos::collections::datetime::tstz_span_set::TsTzSpanSet;
+    /// # use meos::meos_initialize;
+    /// use chrono::{TimeZone, Utc};
+    /// use std::str::FromStr;
+    /// # meos_initialize("UTC");
+    /// let hours = TsTzSpanSet::from_str("{[2020-01-01 08:00:00+00, 2020-01-01 09:00:00+00], [2020-01-01 12:00:00+00, 2020-01-01 13:00:00+00]}").unwrap();
+    /// assert!(hours.contains(&Utc.with_ymd_and_hms(2020, 1, 1, 8, 30, 0).unwrap()));
+    /// assert!(!hours.contains(&Utc.with_ymd_and_hms(2020, 1, 1, 10, 30, 0).unwrap()));
+    /// assert!(hours.contains(&Utc.with_ymd_and_hms(2020, 1, 1, 12, 30, 0).unwrap()));
+    /// ```
     fn contains(&self, content: &DateTime<Utc>) -> bool {
-        unsafe { meos_sys::contains_spanset_date(self.inner(), content.num_days_from_ce()) }
+        unsafe { meos_sys::contains_spanset_timestamptz(self.inner(), to_meos_timestamp(content)) }
     }
 }
 
@@ -56,6 +72,14 @@ impl span_set::SpanSet for TsTzSpanSet {
         unimplemented!("Not implemented for date")
     }
 
+    fn lower_bound(&self) -> Self::Type {
+        from_meos_timestamp(unsafe { meos_sys::tstzspanset_lower(self.inner()) })
+    }
+
+    fn upper_bound(&self) -> Self::Type {
+        from_meos_timestamp(unsafe { meos_sys::tstzspanset_upper(self.inner()) })
+    }
+
     /// Return a new `TsTzSpanSet` with the lower and upper bounds shifted by `delta`.
     ///
     /// ## Arguments
@@ -73,7 +97,7 @@ impl span_set::SpanSet for TsTzSpanSet {
     /// use chrono::TimeDelta;
     /// # meos_initialize("UTC");
     /// let span_set = TsTzSpanSet::from_str("{[2019-09-08 00:00:00+00, 2019-09-10 00:00:00+00], [2019-09-16 00:00:00+00, 2019-09-20 00:00:00+00]}").unwrap();
-    /// let shifted_span_set = span_set.shift(TimeDelta::days(5));
+    /// let shifted_span_set = span_set.shift(TimeDelta::days(10));
     ///
     /// let expected_shifted_span_set =
     ///     TsTzSpanSet::from_str("{[2019-09-18 00:00:00+00, 2019-09-20 00:00:00+00], [2019-09-26 00:00:00+00, 2019-09-30 00:00:00+00]}").unwrap();
@@ -100,7 +124,7 @@ impl span_set::SpanSet for TsTzSpanSet {
     /// use chrono::TimeDelta;
     /// # meos_initialize("UTC");
     /// let span_set = TsTzSpanSet::from_str("{[2019-09-08 00:00:00+00, 2019-09-10 00:00:00+00], [2019-09-13 00:00:00+00, 2019-09-15 00:00:00+00]}").unwrap();
-    /// let scaled_span_set = span_set.scale(TimeDelta::days(5));
+    /// let scaled_span_set = span_set.scale(TimeDelta::days(10));
     ///
     /// let expected_scaled_span_set =
     ///     TsTzSpanSet::from_str("{[2019-09-08 00:00:00+00, 2019-09-10 20:34:17.142857+00], [2019-09-15 03:25:42.857142+00, 2019-09-18 00:00:00+00]}").unwrap();
@@ -128,7 +152,7 @@ impl span_set::SpanSet for TsTzSpanSet {
     /// use chrono::TimeDelta;
     /// # meos_initialize("UTC");
     /// let span_set = TsTzSpanSet::from_str("{[2019-09-08 00:00:00+00, 2019-09-10 00:00:00+00], [2019-09-11 00:00:00+00, 2019-09-12 00:00:00+00]}").unwrap();
-    /// let shifted_scaled_span_set = span_set.shift_scale(Some(TimeDelta::days(5)), Some(TimeDelta::days(10)));
+    /// let shifted_scaled_span_set = span_set.shift_scale(Some(TimeDelta::days(10)), Some(TimeDelta::days(20)));
     ///
     /// let expected_shifted_scaled_span_set =
     ///     TsTzSpanSet::from_str("{[2019-09-18 00:00:00+00, 2019-09-28 00:00:00+00], [2019-10-03 00:00:00+00, 2019-10-08 00:00:00+00]}").unwrap();
@@ -137,7 +161,7 @@ impl span_set::SpanSet for TsTzSpanSet {
     fn shift_scale(&self, delta: Option<TimeDelta>, width: Option<TimeDelta>) -> TsTzSpanSet {
         let d = {
             if let Some(d) = delta {
-                &*Box::new(create_interval(d)) as *const meos_sys::Interval
+                &*Box::new(duration_to_interval(d)) as *const meos_sys::Interval
             } else {
                 std::ptr::null()
             }
@@ -145,7 +169,7 @@ impl span_set::SpanSet for TsTzSpanSet {
 
         let w = {
             if let Some(w) = width {
-                &*Box::new(create_interval(w)) as *const meos_sys::Interval
+                &*Box::new(duration_to_interval(w)) as *const meos_sys::Interval
             } else {
                 std::ptr::null()
             }
@@ -247,6 +271,117 @@ impl span_set::SpanSet for TsTzSpanSet {
     }
 }
 
+impl TsTzSpanSet {
+    /// Returns the textual representation of the span set with its bounds in the timezone
+    /// `tz`, instead of the one MEOS was initialized with.
+    ///
+    /// ## Arguments
+    /// * `tz` - The timezone, e.g. `Utc` or a `chrono_tz::Tz`.
+    ///
+    /// ## Example
+    /// ```
+    /// # use meos::collections::datetime::tstz_span_set::TsTzSpanSet;
+    /// # use meos::meos_initialize;
+    /// # use chrono::Utc;
+    /// # use std::str::FromStr;
+    /// # meos_initialize("UTC");
+    /// let span_set = TsTzSpanSet::from_str("{[2019-07-08 10:00:00+00, 2019-07-10 10:00:00+00]}").unwrap();
+    /// assert_eq!(span_set.to_string_in_tz(&Utc), "{[2019-07-08 10:00:00+00, 2019-07-10 10:00:00+00]}");
+    /// assert_eq!(span_set.to_string_in_tz(&chrono_tz::Europe::Paris), "{[2019-07-08 12:00:00+02, 2019-07-10 12:00:00+02]}");
+    /// ```
+    pub fn to_string_in_tz<Tz: TimeZone>(&self, tz: &Tz) -> String {
+        let spans: Vec<String> = self
+            .spans()
+            .iter()
+            .map(|span| span.to_string_in_tz(tz))
+            .collect();
+        format!("{{{}}}", spans.join(", "))
+    }
+
+    /// Computes the free time of a schedule: the part of `self` inside `within` that does not
+    /// overlap any of the `busy` periods.
+    ///
+    /// ## Arguments
+    /// * `busy` - The periods that are already taken.
+    /// * `within` - The window to look for free time in, e.g. a day.
+    ///
+    /// ## Returns
+    /// * `Some(TsTzSpanSet)` - The free periods.
+    /// * `None` - If there is no free time left in the window.
+    ///
+    /// ## Example
+    /// ```
+    /// # use meos::collections::datetime::tstz_span_set::TsTzSpanSet;
+    /// # use meos::collections::datetime::tstz_span::TsTzSpan;
+    /// # use meos::meos_initialize;
+    /// # use std::str::FromStr;
+    /// # meos_initialize("UTC");
+    /// let working_hours = TsTzSpanSet::from_str("{[2019-09-09 08:00:00+00, 2019-09-09 18:00:00+00]}").unwrap();
+    /// let busy = TsTzSpanSet::from_str("{[2019-09-09 09:00:00+00, 2019-09-09 10:30:00+00], [2019-09-09 13:00:00+00, 2019-09-09 14:00:00+00]}").unwrap();
+    /// let day = TsTzSpan::from_str("[2019-09-09 00:00:00+00, 2019-09-10 00:00:00+00)").unwrap();
+    ///
+    /// let expected = TsTzSpanSet::from_str("{[2019-09-09 08:00:00+00, 2019-09-09 09:00:00+00), (2019-09-09 10:30:00+00, 2019-09-09 13:00:00+00), (2019-09-09 14:00:00+00, 2019-09-09 18:00:00+00]}").unwrap();
+    /// assert_eq!(working_hours.available(&busy, &day).unwrap(), expected);
+    /// ```
+    ///
+    /// MEOS Functions:
+    ///     `intersection_spanset_span`, `minus_spanset_spanset`
+    pub fn available(&self, busy: &TsTzSpanSet, within: &TsTzSpan) -> Option<TsTzSpanSet> {
+        unsafe {
+            let candidate = meos_sys::intersection_spanset_span(self.inner(), within.inner());
+            if candidate.is_null() {
+                return None;
+            }
+            let free = meos_sys::minus_spanset_spanset(candidate, busy.inner());
+            libc::free(candidate as *mut c_void);
+            (!free.is_null()).then(|| Self::from_inner(free))
+        }
+    }
+
+    /// Computes the Jaccard similarity of the time covered by `self` and `other`: the duration
+    /// of their intersection divided by the duration of their union, e.g. to compare the uptime
+    /// of two sensors.
+    ///
+    /// ## Arguments
+    /// * `other` - The span set to compare with.
+    ///
+    /// ## Returns
+    /// A similarity between `0.0` for disjoint span sets and `1.0` for equal ones, or `0.0` if
+    /// their union has no duration.
+    ///
+    /// ## Example
+    /// ```
+    /// # use meos::collections::datetime::tstz_span_set::TsTzSpanSet;
+    /// # use meos::meos_initialize;
+    /// # use std::str::FromStr;
+    /// # meos_initialize("UTC");
+    /// let first = TsTzSpanSet::from_str("{[2019-09-09 08:00:00+00, 2019-09-09 12:00:00+00]}").unwrap();
+    /// let second = TsTzSpanSet::from_str("{[2019-09-09 10:00:00+00, 2019-09-09 14:00:00+00]}").unwrap();
+    /// let disjoint = TsTzSpanSet::from_str("{[2019-09-10 08:00:00+00, 2019-09-10 12:00:00+00]}").unwrap();
+    /// assert_eq!(first.jaccard(&first), 1.0);
+    /// assert_eq!(first.jaccard(&second), 2.0 / 6.0);
+    /// assert_eq!(first.jaccard(&disjoint), 0.0);
+    /// ```
+    ///
+    /// MEOS Functions:
+    ///     `intersection_spanset_spanset`, `union_spanset_spanset`, `tstzspanset_duration`
+    pub fn jaccard(&self, other: &TsTzSpanSet) -> f64 {
+        let duration = |span_set: Option<TsTzSpanSet>| {
+            span_set.map_or(0, |span_set| unsafe {
+                let interval = meos_sys::tstzspanset_duration(span_set.inner(), false);
+                let duration = interval_to_duration(interval.read());
+                libc::free(interval as *mut c_void);
+                duration.num_microseconds().unwrap()
+            })
+        };
+        let union = duration(self.union(other));
+        if union == 0 {
+            return 0.0;
+        }
+        duration(self.intersection(other)) as f64 / union as f64
+    }
+}
+
 impl Clone for TsTzSpanSet {
     fn clone(&self) -> TsTzSpanSet {
         self.copy()
@@ -280,6 +415,10 @@ impl std::cmp::PartialEq for TsTzSpanSet {
     }
 }
 
+impl_serde!(TsTzSpanSet, |span_set| unsafe {
+    meos_sys::tstzspanset_out(span_set.inner())
+});
+
 impl Debug for TsTzSpanSet {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let out_str = unsafe { meos_sys::tstzspanset_out(self.inner()) };
@@ -1,4 +1,6 @@
+pub mod any;
 pub mod base;
 pub mod datetime;
 pub mod geo;
 pub mod number;
+pub mod set;
@@ -10,7 +10,11 @@ use std::{
 use collection::{impl_collection, Collection};
 use span::Span;
 
-use crate::{collections::base::*, errors::ParseError};
+use crate::{
+    collections::base::*,
+    errors::{ParseError, SpanBoundsError},
+    impl_serde,
+};
 
 use super::number_span::NumberSpan;
 
@@ -52,6 +56,12 @@ impl span::Span for FloatSpan {
         }
     }
 
+    fn from_bounds(lower: f64, upper: f64, lower_inclusive: bool, upper_inclusive: bool) -> Self {
+        let inner =
+            unsafe { meos_sys::floatspan_make(lower, upper, lower_inclusive, upper_inclusive) };
+        Self::from_inner(inner)
+    }
+
     /// Returns the lower bound of the span.
     ///
     /// ## Returns
@@ -170,6 +180,81 @@ impl span::Span for FloatSpan {
 
 impl NumberSpan for FloatSpan {}
 
+#[cfg(feature = "num-traits")]
+impl super::number_span::NumericSpan for FloatSpan {}
+
+impl FloatSpan {
+    /// Creates a new `FloatSpan` from its bounds, checking that they define a valid span.
+    ///
+    /// Unlike `from_bounds`, non-finite bounds or bounds that do not enclose any value are
+    /// reported as an error instead of being passed to MEOS.
+    ///
+    /// ## Arguments
+    /// * `lower` - The lower bound.
+    /// * `upper` - The upper bound.
+    /// * `lower_inclusive` - Whether the lower bound is inclusive.
+    /// * `upper_inclusive` - Whether the upper bound is inclusive.
+    ///
+    /// ## Returns
+    /// * A new `FloatSpan`, or a `SpanBoundsError` if the bounds are not valid.
+    ///
+    /// ## Example
+    /// ```
+    /// # use meos::collections::number::float_span::FloatSpan;
+    /// # use meos::errors::SpanBoundsError;
+    ///
+    /// assert!(FloatSpan::try_from_bounds(1.0, 2.0, true, false).is_ok());
+    /// assert_eq!(FloatSpan::try_from_bounds(f64::NAN, 1.0, true, false), Err(SpanBoundsError::NonFinite));
+    /// assert_eq!(FloatSpan::try_from_bounds(1.0, f64::INFINITY, true, false), Err(SpanBoundsError::NonFinite));
+    /// assert_eq!(FloatSpan::try_from_bounds(2.0, 1.0, true, false), Err(SpanBoundsError::LowerAboveUpper));
+    /// assert_eq!(FloatSpan::try_from_bounds(1.0, 1.0, true, false), Err(SpanBoundsError::Empty));
+    /// ```
+    pub fn try_from_bounds(
+        lower: f64,
+        upper: f64,
+        lower_inclusive: bool,
+        upper_inclusive: bool,
+    ) -> Result<Self, SpanBoundsError> {
+        if !lower.is_finite() || !upper.is_finite() {
+            Err(SpanBoundsError::NonFinite)
+        } else if lower > upper {
+            Err(SpanBoundsError::LowerAboveUpper)
+        } else if lower == upper && !(lower_inclusive && upper_inclusive) {
+            Err(SpanBoundsError::Empty)
+        } else {
+            Ok(Self::from_bounds(
+                lower,
+                upper,
+                lower_inclusive,
+                upper_inclusive,
+            ))
+        }
+    }
+
+    /// Creates a new `FloatSpan` from a half-open range, checking that it defines a valid span.
+    ///
+    /// The `From<Range<f64>>` conversion panics on the ranges this function rejects, see its
+    /// `Panics` section.
+    ///
+    /// ## Arguments
+    /// * `range` - The range of values, with an inclusive start and an exclusive end.
+    ///
+    /// ## Returns
+    /// * A new `FloatSpan`, or a `SpanBoundsError` if the range is not valid.
+    ///
+    /// ## Example
+    /// ```
+    /// # use meos::collections::number::float_span::FloatSpan;
+    /// # use meos::errors::SpanBoundsError;
+    ///
+    /// assert_eq!(FloatSpan::try_from_range(f64::NAN..1.0), Err(SpanBoundsError::NonFinite));
+    /// assert_eq!(FloatSpan::try_from_range(0.5..1.0), Ok((0.5..1.0).into()));
+    /// ```
+    pub fn try_from_range(Range { start, end }: Range<f64>) -> Result<Self, SpanBoundsError> {
+        Self::try_from_bounds(start, end, true, false)
+    }
+}
+
 impl Clone for FloatSpan {
     fn clone(&self) -> Self {
         unsafe { Self::from_inner(meos_sys::span_copy(self.inner())) }
@@ -242,36 +327,56 @@ impl cmp::PartialEq for FloatSpan {
 
 impl cmp::Eq for FloatSpan {}
 
+/// Converts a half-open range into a span, use `FloatSpan::try_from_range` to handle invalid
+/// ranges instead.
+///
+/// ## Panics
+/// If a bound is not finite, or the start is not less than the end.
 impl From<Range<f64>> for FloatSpan {
     fn from(Range { start, end }: Range<f64>) -> Self {
-        let inner = unsafe { meos_sys::floatspan_make(start, end, true, false) };
-        Self::from_inner(inner)
+        Self::try_from_bounds(start, end, true, false).expect("Invalid span bounds")
     }
 }
 
+/// Converts a half-open range into a span, use `FloatSpan::try_from_bounds` to handle invalid
+/// ranges instead.
+///
+/// ## Panics
+/// If a bound is not finite, or the start is not less than the end.
 impl From<Range<f32>> for FloatSpan {
     fn from(Range { start, end }: Range<f32>) -> Self {
-        let inner = unsafe { meos_sys::floatspan_make(start as f64, end as f64, true, false) };
-        Self::from_inner(inner)
+        Self::try_from_bounds(start as f64, end as f64, true, false).expect("Invalid span bounds")
     }
 }
 
+/// Converts a closed range into a span, use `FloatSpan::try_from_bounds` to handle invalid
+/// ranges instead.
+///
+/// ## Panics
+/// If a bound is not finite, or the start is greater than the end.
 impl From<RangeInclusive<f64>> for FloatSpan {
     fn from(range: RangeInclusive<f64>) -> Self {
-        let inner = unsafe { meos_sys::floatspan_make(*range.start(), *range.end(), true, true) };
-        Self::from_inner(inner)
+        Self::try_from_bounds(*range.start(), *range.end(), true, true)
+            .expect("Invalid span bounds")
     }
 }
 
+/// Converts a closed range into a span, use `FloatSpan::try_from_bounds` to handle invalid
+/// ranges instead.
+///
+/// ## Panics
+/// If a bound is not finite, or the start is greater than the end.
 impl From<RangeInclusive<f32>> for FloatSpan {
     fn from(range: RangeInclusive<f32>) -> Self {
-        let inner = unsafe {
-            meos_sys::floatspan_make(*range.start() as f64, *range.end() as f64, true, true)
-        };
-        Self::from_inner(inner)
+        Self::try_from_bounds(*range.start() as f64, *range.end() as f64, true, true)
+            .expect("Invalid span bounds")
     }
 }
 
+impl_serde!(FloatSpan, |span| unsafe {
+    meos_sys::floatspan_out(span.inner(), 15)
+});
+
 impl Debug for FloatSpan {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let out_str = unsafe { meos_sys::floatspan_out(self.inner(), 3) };
@@ -12,6 +12,7 @@ use crate::collections::base::span::Span;
 use crate::collections::base::span_set::SpanSet;
 use crate::collections::base::*;
 use crate::errors::ParseError;
+use crate::impl_serde;
 
 use super::float_span::FloatSpan;
 use super::number_span_set::NumberSpanSet;
@@ -53,6 +54,14 @@ impl span_set::SpanSet for FloatSpanSet {
         unsafe { meos_sys::floatspanset_width(self.inner(), ignore_gaps) }
     }
 
+    fn lower_bound(&self) -> Self::Type {
+        unsafe { meos_sys::floatspanset_lower(self.inner()) }
+    }
+
+    fn upper_bound(&self) -> Self::Type {
+        unsafe { meos_sys::floatspanset_upper(self.inner()) }
+    }
+
     /// Return a new `FloatSpanSet` with the lower and upper bounds shifted by `delta`.
     ///
     /// ## Arguments
@@ -238,6 +247,10 @@ impl std::cmp::PartialEq for FloatSpanSet {
     }
 }
 
+impl_serde!(FloatSpanSet, |span_set| unsafe {
+    meos_sys::floatspanset_out(span_set.inner(), 15)
+});
+
 impl Debug for FloatSpanSet {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let out_str = unsafe { meos_sys::floatspanset_out(self.inner(), 3) };
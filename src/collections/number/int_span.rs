@@ -10,6 +10,7 @@ use std::{
 use collection::{impl_collection, Collection};
 use span::Span;
 
+use crate::impl_serde;
 use crate::{collections::base::*, errors::ParseError};
 
 use super::number_span::NumberSpan;
@@ -52,6 +53,12 @@ impl span::Span for IntSpan {
         }
     }
 
+    fn from_bounds(lower: i32, upper: i32, lower_inclusive: bool, upper_inclusive: bool) -> Self {
+        let inner =
+            unsafe { meos_sys::intspan_make(lower, upper, lower_inclusive, upper_inclusive) };
+        Self::from_inner(inner)
+    }
+
     /// Returns the lower bound of the span.
     ///
     /// ## Returns
@@ -207,6 +214,57 @@ impl span::Span for IntSpan {
 
 impl NumberSpan for IntSpan {}
 
+#[cfg(feature = "num-traits")]
+impl super::number_span::NumericSpan for IntSpan {}
+
+impl IntSpan {
+    /// Returns whether `self` is strictly before `value`.
+    ///
+    /// ## Arguments
+    /// * `value` - The integer to compare with.
+    ///
+    /// ## Returns
+    /// `true` if every value of the span is lower than `value`.
+    ///
+    /// ## Example
+    /// ```
+    /// # use meos::collections::number::int_span::IntSpan;
+    ///
+    /// let span: IntSpan = (1..10).into();
+    /// assert!(span.is_before_value(20));
+    /// assert!(!span.is_before_value(5));
+    /// ```
+    ///
+    /// MEOS Functions:
+    ///     `left_span_int`
+    pub fn is_before_value(&self, value: i32) -> bool {
+        unsafe { meos_sys::left_span_int(self.inner(), value) }
+    }
+
+    /// Returns whether `self` is strictly after `value`.
+    ///
+    /// ## Arguments
+    /// * `value` - The integer to compare with.
+    ///
+    /// ## Returns
+    /// `true` if every value of the span is greater than `value`.
+    ///
+    /// ## Example
+    /// ```
+    /// # use meos::collections::number::int_span::IntSpan;
+    ///
+    /// let span: IntSpan = (1..10).into();
+    /// assert!(span.is_after_value(0));
+    /// assert!(!span.is_after_value(5));
+    /// ```
+    ///
+    /// MEOS Functions:
+    ///     `right_span_int`
+    pub fn is_after_value(&self, value: i32) -> bool {
+        unsafe { meos_sys::right_span_int(self.inner(), value) }
+    }
+}
+
 impl Clone for IntSpan {
     fn clone(&self) -> Self {
         unsafe { Self::from_inner(meos_sys::span_copy(self.inner())) }
@@ -302,6 +360,10 @@ impl From<RangeInclusive<f32>> for IntSpan {
     }
 }
 
+impl_serde!(IntSpan, |span| unsafe {
+    meos_sys::intspan_out(span.inner())
+});
+
 impl Debug for IntSpan {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let out_str = unsafe { meos_sys::intspan_out(self.inner()) };
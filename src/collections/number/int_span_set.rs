@@ -11,7 +11,8 @@ use span_set::impl_iterator;
 
 use crate::collections::base::span_set::SpanSet;
 use crate::collections::base::*;
-use crate::errors::ParseError;
+use crate::errors::{ParseError, TooLargeError};
+use crate::impl_serde;
 
 use super::int_span::IntSpan;
 use super::number_span_set::NumberSpanSet;
@@ -52,6 +53,14 @@ impl span_set::SpanSet for IntSpanSet {
         unsafe { meos_sys::intspanset_width(self.inner(), ignore_gaps) }
     }
 
+    fn lower_bound(&self) -> Self::Type {
+        unsafe { meos_sys::intspanset_lower(self.inner()) }
+    }
+
+    fn upper_bound(&self) -> Self::Type {
+        unsafe { meos_sys::intspanset_upper(self.inner()) }
+    }
+
     /// Return a new `IntSpanSet` with the lower and upper bounds shifted by `delta`.
     ///
     /// ## Arguments
@@ -204,6 +213,63 @@ impl span_set::SpanSet for IntSpanSet {
 
 impl NumberSpanSet for IntSpanSet {}
 
+impl IntSpanSet {
+    /// Returns a lazy iterator over every integer contained in the span set, in increasing order.
+    ///
+    /// ## Example
+    /// ```
+    /// # use meos::collections::number::int_span_set::IntSpanSet;
+    /// # use meos::meos_initialize;
+    /// # use std::str::FromStr;
+    /// # meos_initialize("UTC");
+    /// let span_set = IntSpanSet::from_str("{[1, 3), [7, 9)}").unwrap();
+    /// assert_eq!(span_set.iter_values().collect::<Vec<_>>(), vec![1, 2, 7, 8]);
+    ///
+    /// let huge = IntSpanSet::from_str("{[0, 2000000000)}").unwrap();
+    /// assert_eq!(huge.iter_values().take(3).collect::<Vec<_>>(), vec![0, 1, 2]);
+    /// ```
+    pub fn iter_values(&self) -> impl Iterator<Item = i32> + '_ {
+        // MEOS span indexes are 1-based
+        (1..=self.num_spans()).flat_map(move |n| {
+            let span = self.span_n(n);
+            span.lower()..span.upper()
+        })
+    }
+
+    /// Returns every integer contained in the span set, unless there are more than `limit`.
+    ///
+    /// ## Arguments
+    /// * `limit` - Maximum number of values to materialize.
+    ///
+    /// ## Returns
+    /// The values in increasing order, or a `TooLargeError` if the span set holds more than `limit` values.
+    ///
+    /// ## Example
+    /// ```
+    /// # use meos::collections::number::int_span_set::IntSpanSet;
+    /// # use meos::meos_initialize;
+    /// # use std::str::FromStr;
+    /// # meos_initialize("UTC");
+    /// let span_set = IntSpanSet::from_str("{[1, 3), [7, 9)}").unwrap();
+    /// assert_eq!(span_set.try_values(1000), Ok(vec![1, 2, 7, 8]));
+    ///
+    /// let huge = IntSpanSet::from_str("{[0, 2000000000)}").unwrap();
+    /// assert!(huge.try_values(1000).is_err());
+    /// ```
+    pub fn try_values(&self, limit: usize) -> Result<Vec<i32>, TooLargeError> {
+        let count = (1..=self.num_spans())
+            .map(|n| {
+                let span = self.span_n(n);
+                (span.upper() as i64 - span.lower() as i64) as u64
+            })
+            .sum();
+        if count > limit as u64 {
+            return Err(TooLargeError { count, limit });
+        }
+        Ok(self.iter_values().collect())
+    }
+}
+
 impl Clone for IntSpanSet {
     fn clone(&self) -> IntSpanSet {
         self.copy()
@@ -237,6 +303,10 @@ impl std::cmp::PartialEq for IntSpanSet {
     }
 }
 
+impl_serde!(IntSpanSet, |span_set| unsafe {
+    meos_sys::intspanset_out(span_set.inner())
+});
+
 impl Debug for IntSpanSet {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let out_str = unsafe { meos_sys::intspanset_out(self.inner()) };
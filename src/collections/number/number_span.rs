@@ -2,3 +2,29 @@ use crate::collections::base::span::Span;
 
 /// You shouldn't probably implement this trait yourself, it's just to run some functions in both IntSpan and FloatSpan
 pub trait NumberSpan: Span {}
+
+/// A number span whose bounds are `num_traits` numbers, to write algorithms once for both
+/// `IntSpan` and `FloatSpan`.
+///
+/// ## Example
+/// ```
+/// # use meos::collections::base::span::Span;
+/// # use meos::collections::number::{float_span::FloatSpan, int_span::IntSpan, number_span::NumericSpan};
+/// fn relative_width<S: NumericSpan>(span: &S) -> S::Type {
+///     span.width() / span.upper()
+/// }
+///
+/// let int_span: IntSpan = (2..10).into();
+/// let float_span: FloatSpan = (2.0..10.0).into();
+/// assert_eq!(int_span.width(), 8);
+/// assert_eq!(float_span.width(), 8.0);
+/// assert_eq!(relative_width(&int_span), 0);
+/// assert_eq!(relative_width(&float_span), 0.8);
+/// ```
+#[cfg(feature = "num-traits")]
+pub trait NumericSpan: NumberSpan<Type: num_traits::Num + Copy> {
+    /// Returns the distance between the lower and upper bounds of the span.
+    fn width(&self) -> Self::Type {
+        self.upper() - self.lower()
+    }
+}
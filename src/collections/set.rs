@@ -0,0 +1,472 @@
+use std::ffi::{c_char, c_void, CStr, CString};
+use std::fmt::Debug;
+use std::hash::Hash;
+use std::marker::PhantomData;
+use std::ptr;
+
+use chrono::{DateTime, Datelike, NaiveDate, Utc};
+
+use crate::collections::{
+    base::span_set::SpanSet,
+    datetime::{date_span_set::DateSpanSet, tstz_span_set::TsTzSpanSet, DAYS_UNTIL_2000},
+    number::{float_span_set::FloatSpanSet, int_span_set::IntSpanSet},
+};
+use crate::errors::ParseError;
+use crate::utils::{from_meos_timestamp, to_meos_timestamp};
+
+/// A base type that can be stored in a MEOS `Set`.
+///
+/// Every method forwards to the MEOS function of the matching set type, e.g. `intset_in` for
+/// `i32`.
+pub trait SetElement: Sized {
+    /// Parses a set of this type from its textual representation.
+    fn set_in(string: &CStr) -> *mut meos_sys::Set;
+
+    /// Returns the textual representation of a set of this type.
+    fn set_out(set: &Set<Self>) -> *mut c_char;
+
+    /// Creates a set of this type from its values.
+    fn set_make(values: &[Self]) -> *mut meos_sys::Set;
+
+    /// Returns the `n`-th value of a set of this type, starting from 1.
+    fn set_value_n(set: &Set<Self>, n: i32) -> Self;
+
+    /// Returns whether a set of this type contains `value`.
+    fn set_contains(set: &Set<Self>, value: &Self) -> bool;
+}
+
+/// A base type whose sets can be converted into span sets.
+pub trait SpanSetElement: SetElement {
+    /// The span set type holding the same values as a set of this type.
+    type SpanSetType: SpanSet;
+}
+
+/// An ordered collection of distinct values of the same type, e.g. `{1, 3, 5}`.
+///
+/// Unlike span sets, sets are discrete: they only contain the values they are made of.
+///
+/// ## Example
+/// ```
+/// # use meos::collections::set::{DateSet, FloatSet, IntSet, TextSet, TsTzSet};
+/// # use meos::meos_initialize;
+/// # use std::str::FromStr;
+/// # meos_initialize("UTC");
+/// let int_set = IntSet::from_str("{1, 3, 5}").unwrap();
+/// assert_eq!(format!("{int_set:?}"), "{1, 3, 5}");
+///
+/// let float_set = FloatSet::from_str("{1.5, 2.5}").unwrap();
+/// assert_eq!(format!("{float_set:?}"), "{1.5, 2.5}");
+///
+/// let text_set = TextSet::from_str(r#"{"AAA", "BBB"}"#).unwrap();
+/// assert_eq!(format!("{text_set:?}"), r#"{"AAA", "BBB"}"#);
+///
+/// let date_set = DateSet::from_str("{2019-07-08, 2019-07-10}").unwrap();
+/// assert_eq!(format!("{date_set:?}"), "{2019-07-08, 2019-07-10}");
+///
+/// let tstz_set = TsTzSet::from_str("{2019-07-08 10:00:00+00, 2019-07-10 10:00:00+00}").unwrap();
+/// assert_eq!(format!("{tstz_set:?}"), "{2019-07-08 10:00:00+00, 2019-07-10 10:00:00+00}");
+/// ```
+pub struct Set<T: SetElement> {
+    _inner: ptr::NonNull<meos_sys::Set>,
+    _type: PhantomData<T>,
+}
+
+pub type IntSet = Set<i32>;
+pub type FloatSet = Set<f64>;
+pub type TextSet = Set<String>;
+pub type DateSet = Set<NaiveDate>;
+pub type TsTzSet = Set<DateTime<Utc>>;
+
+impl<T: SetElement> Set<T> {
+    pub fn inner(&self) -> *const meos_sys::Set {
+        self._inner.as_ptr()
+    }
+
+    pub fn from_inner(inner: *mut meos_sys::Set) -> Self {
+        Self {
+            _inner: ptr::NonNull::new(inner).expect("Null pointers not allowed"),
+            _type: PhantomData,
+        }
+    }
+
+    fn from_inner_option(inner: *mut meos_sys::Set) -> Option<Self> {
+        ptr::NonNull::new(inner).map(|inner| Self {
+            _inner: inner,
+            _type: PhantomData,
+        })
+    }
+
+    /// Creates a set from its values, which are sorted and deduplicated.
+    ///
+    /// ## Arguments
+    /// * `values` - The values of the set, at least one.
+    ///
+    /// ## Example
+    /// ```
+    /// # use meos::collections::set::IntSet;
+    /// # use meos::meos_initialize;
+    /// # use std::str::FromStr;
+    /// # meos_initialize("UTC");
+    /// let set = IntSet::from_slice(&[5, 1, 3, 1]);
+    /// assert_eq!(set, IntSet::from_str("{1, 3, 5}").unwrap());
+    /// ```
+    ///
+    /// MEOS Functions:
+    ///     `<type>set_make`
+    pub fn from_slice(values: &[T]) -> Self {
+        Self::from_inner(T::set_make(values))
+    }
+
+    /// Returns the number of values in the set.
+    ///
+    /// MEOS Functions:
+    ///     `set_num_values`
+    pub fn num_values(&self) -> i32 {
+        unsafe { meos_sys::set_num_values(self.inner()) }
+    }
+
+    /// Returns the first value of the set.
+    pub fn start_value(&self) -> T {
+        T::set_value_n(self, 1)
+    }
+
+    /// Returns the last value of the set.
+    pub fn end_value(&self) -> T {
+        T::set_value_n(self, self.num_values())
+    }
+
+    /// Returns the `n`-th value of the set, starting from 0.
+    ///
+    /// MEOS Functions:
+    ///     `<type>set_value_n`
+    pub fn value_n(&self, n: i32) -> Option<T> {
+        // MEOS set indexes are 1-based
+        (0..self.num_values())
+            .contains(&n)
+            .then(|| T::set_value_n(self, n + 1))
+    }
+
+    /// Returns an iterator over the values of the set, in increasing order.
+    ///
+    /// ## Example
+    /// ```
+    /// # use meos::collections::set::TextSet;
+    /// # use meos::meos_initialize;
+    /// # use std::str::FromStr;
+    /// # meos_initialize("UTC");
+    /// let set = TextSet::from_str(r#"{"BBB", "AAA"}"#).unwrap();
+    /// assert_eq!(set.iter().collect::<Vec<_>>(), vec!["AAA", "BBB"]);
+    /// ```
+    pub fn iter(&self) -> impl Iterator<Item = T> + '_ {
+        (1..=self.num_values()).map(|n| T::set_value_n(self, n))
+    }
+
+    /// Returns whether the set contains `value`.
+    ///
+    /// ## Example
+    /// ```
+    /// # use meos::collections::set::FloatSet;
+    /// # use meos::meos_initialize;
+    /// # use std::str::FromStr;
+    /// # meos_initialize("UTC");
+    /// let set = FloatSet::from_str("{1.5, 2.5}").unwrap();
+    /// assert!(set.contains(&1.5));
+    /// assert!(!set.contains(&2.0));
+    /// ```
+    ///
+    /// MEOS Functions:
+    ///     `contains_set_<type>`
+    pub fn contains(&self, value: &T) -> bool {
+        T::set_contains(self, value)
+    }
+
+    /// Returns whether every value of `other` is in the set.
+    ///
+    /// MEOS Functions:
+    ///     `contains_set_set`
+    pub fn contains_set(&self, other: &Self) -> bool {
+        unsafe { meos_sys::contains_set_set(self.inner(), other.inner()) }
+    }
+
+    /// Returns the values that are in `self` or in `other`.
+    ///
+    /// MEOS Functions:
+    ///     `union_set_set`
+    pub fn union(&self, other: &Self) -> Option<Self> {
+        Self::from_inner_option(unsafe { meos_sys::union_set_set(self.inner(), other.inner()) })
+    }
+
+    /// Returns the values that are in both `self` and `other`, or `None` if there are none.
+    ///
+    /// ## Example
+    /// ```
+    /// # use meos::collections::set::IntSet;
+    /// # use meos::meos_initialize;
+    /// # meos_initialize("UTC");
+    /// let set = IntSet::from_slice(&[1, 2, 3]);
+    /// assert_eq!(set.intersection(&IntSet::from_slice(&[2, 3, 4])), Some(IntSet::from_slice(&[2, 3])));
+    /// assert_eq!(set.intersection(&IntSet::from_slice(&[5])), None);
+    /// ```
+    ///
+    /// MEOS Functions:
+    ///     `intersection_set_set`
+    pub fn intersection(&self, other: &Self) -> Option<Self> {
+        Self::from_inner_option(unsafe {
+            meos_sys::intersection_set_set(self.inner(), other.inner())
+        })
+    }
+
+    /// Returns the values of `self` that are not in `other`, or `None` if there are none.
+    ///
+    /// ## Example
+    /// ```
+    /// # use meos::collections::set::IntSet;
+    /// # use meos::meos_initialize;
+    /// # meos_initialize("UTC");
+    /// let set = IntSet::from_slice(&[1, 2, 3]);
+    /// assert_eq!(set.minus(&IntSet::from_slice(&[2])), Some(IntSet::from_slice(&[1, 3])));
+    /// assert_eq!(set.minus(&set), None);
+    /// ```
+    ///
+    /// MEOS Functions:
+    ///     `minus_set_set`
+    pub fn minus(&self, other: &Self) -> Option<Self> {
+        Self::from_inner_option(unsafe { meos_sys::minus_set_set(self.inner(), other.inner()) })
+    }
+}
+
+impl<T: SpanSetElement> Set<T> {
+    /// Returns the span set holding the same values as the set, with an instantaneous span
+    /// for every value.
+    ///
+    /// ## Example
+    /// ```
+    /// # use meos::collections::set::IntSet;
+    /// # use meos::collections::number::int_span_set::IntSpanSet;
+    /// # use meos::meos_initialize;
+    /// # use std::str::FromStr;
+    /// # meos_initialize("UTC");
+    /// let set = IntSet::from_slice(&[1, 2, 5]);
+    /// assert_eq!(set.to_span_set(), IntSpanSet::from_str("{[1, 3), [5, 6)}").unwrap());
+    /// ```
+    ///
+    /// MEOS Functions:
+    ///     `set_to_spanset`
+    pub fn to_span_set(&self) -> T::SpanSetType {
+        T::SpanSetType::from_inner(unsafe { meos_sys::set_to_spanset(self.inner()) })
+    }
+}
+
+impl<T: SetElement> Drop for Set<T> {
+    fn drop(&mut self) {
+        unsafe {
+            libc::free(self._inner.as_ptr() as *mut c_void);
+        }
+    }
+}
+
+impl<T: SetElement> Clone for Set<T> {
+    fn clone(&self) -> Self {
+        Self::from_inner(unsafe { meos_sys::set_copy(self.inner()) })
+    }
+}
+
+impl<T: SetElement> Hash for Set<T> {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        let hash = unsafe { meos_sys::set_hash(self.inner()) };
+        state.write_u32(hash);
+    }
+}
+
+impl<T: SetElement> std::str::FromStr for Set<T> {
+    type Err = ParseError;
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        let string = CString::new(string).map_err(|_| ParseError)?;
+        Self::from_inner_option(T::set_in(&string)).ok_or(ParseError)
+    }
+}
+
+impl<T: SetElement> std::cmp::PartialEq for Set<T> {
+    fn eq(&self, other: &Self) -> bool {
+        unsafe { meos_sys::set_eq(self.inner(), other.inner()) }
+    }
+}
+
+impl<T: SetElement> Debug for Set<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let out_str = T::set_out(self);
+        let c_str = unsafe { CStr::from_ptr(out_str) };
+        let str = c_str.to_str().map_err(|_| std::fmt::Error);
+        let result = str.and_then(|s| f.write_str(s));
+        unsafe { libc::free(out_str as *mut c_void) };
+        result
+    }
+}
+
+impl SetElement for i32 {
+    fn set_in(string: &CStr) -> *mut meos_sys::Set {
+        unsafe { meos_sys::intset_in(string.as_ptr()) }
+    }
+
+    fn set_out(set: &Set<Self>) -> *mut c_char {
+        unsafe { meos_sys::intset_out(set.inner()) }
+    }
+
+    fn set_make(values: &[Self]) -> *mut meos_sys::Set {
+        unsafe { meos_sys::intset_make(values.as_ptr(), values.len() as i32) }
+    }
+
+    fn set_value_n(set: &Set<Self>, n: i32) -> Self {
+        let mut value = 0;
+        unsafe { meos_sys::intset_value_n(set.inner(), n, ptr::addr_of_mut!(value)) };
+        value
+    }
+
+    fn set_contains(set: &Set<Self>, value: &Self) -> bool {
+        unsafe { meos_sys::contains_set_int(set.inner(), *value) }
+    }
+}
+
+impl SpanSetElement for i32 {
+    type SpanSetType = IntSpanSet;
+}
+
+impl SetElement for f64 {
+    fn set_in(string: &CStr) -> *mut meos_sys::Set {
+        unsafe { meos_sys::floatset_in(string.as_ptr()) }
+    }
+
+    fn set_out(set: &Set<Self>) -> *mut c_char {
+        unsafe { meos_sys::floatset_out(set.inner(), 3) }
+    }
+
+    fn set_make(values: &[Self]) -> *mut meos_sys::Set {
+        unsafe { meos_sys::floatset_make(values.as_ptr(), values.len() as i32) }
+    }
+
+    fn set_value_n(set: &Set<Self>, n: i32) -> Self {
+        let mut value = 0.0;
+        unsafe { meos_sys::floatset_value_n(set.inner(), n, ptr::addr_of_mut!(value)) };
+        value
+    }
+
+    fn set_contains(set: &Set<Self>, value: &Self) -> bool {
+        unsafe { meos_sys::contains_set_float(set.inner(), *value) }
+    }
+}
+
+impl SpanSetElement for f64 {
+    type SpanSetType = FloatSpanSet;
+}
+
+fn to_ctext(string: &str) -> *mut meos_sys::text {
+    let cstr = CString::new(string).unwrap();
+    unsafe { meos_sys::cstring2text(cstr.as_ptr()) }
+}
+
+impl SetElement for String {
+    fn set_in(string: &CStr) -> *mut meos_sys::Set {
+        unsafe { meos_sys::textset_in(string.as_ptr()) }
+    }
+
+    fn set_out(set: &Set<Self>) -> *mut c_char {
+        unsafe { meos_sys::textset_out(set.inner()) }
+    }
+
+    fn set_make(values: &[Self]) -> *mut meos_sys::Set {
+        let mut texts: Vec<*const meos_sys::text> = values
+            .iter()
+            .map(|value| to_ctext(value) as *const _)
+            .collect();
+        let set = unsafe { meos_sys::textset_make(texts.as_mut_ptr(), texts.len() as i32) };
+        for text in texts {
+            unsafe { libc::free(text as *mut c_void) };
+        }
+        set
+    }
+
+    fn set_value_n(set: &Set<Self>, n: i32) -> Self {
+        unsafe {
+            let mut text = ptr::null_mut();
+            meos_sys::textset_value_n(set.inner(), n, ptr::addr_of_mut!(text));
+            let cstr = meos_sys::text2cstring(text);
+            let result = CStr::from_ptr(cstr).to_str().unwrap().to_owned();
+            libc::free(cstr as *mut c_void);
+            result
+        }
+    }
+
+    fn set_contains(set: &Set<Self>, value: &Self) -> bool {
+        let text = to_ctext(value);
+        let result = unsafe { meos_sys::contains_set_text(set.inner(), text) };
+        unsafe { libc::free(text as *mut c_void) };
+        result
+    }
+}
+
+fn to_meos_date(date: &NaiveDate) -> meos_sys::DateADT {
+    date.checked_sub_days(DAYS_UNTIL_2000)
+        .unwrap()
+        .num_days_from_ce()
+}
+
+impl SetElement for NaiveDate {
+    fn set_in(string: &CStr) -> *mut meos_sys::Set {
+        unsafe { meos_sys::dateset_in(string.as_ptr()) }
+    }
+
+    fn set_out(set: &Set<Self>) -> *mut c_char {
+        unsafe { meos_sys::dateset_out(set.inner()) }
+    }
+
+    fn set_make(values: &[Self]) -> *mut meos_sys::Set {
+        let dates: Vec<_> = values.iter().map(to_meos_date).collect();
+        unsafe { meos_sys::dateset_make(dates.as_ptr(), dates.len() as i32) }
+    }
+
+    fn set_value_n(set: &Set<Self>, n: i32) -> Self {
+        let mut value = 0;
+        unsafe { meos_sys::dateset_value_n(set.inner(), n, ptr::addr_of_mut!(value)) };
+        NaiveDate::from_num_days_from_ce_opt(value)
+            .expect("Wrong date returned from meos")
+            .checked_add_days(DAYS_UNTIL_2000)
+            .unwrap()
+    }
+
+    fn set_contains(set: &Set<Self>, value: &Self) -> bool {
+        unsafe { meos_sys::contains_set_date(set.inner(), to_meos_date(value)) }
+    }
+}
+
+impl SpanSetElement for NaiveDate {
+    type SpanSetType = DateSpanSet;
+}
+
+impl SetElement for DateTime<Utc> {
+    fn set_in(string: &CStr) -> *mut meos_sys::Set {
+        unsafe { meos_sys::tstzset_in(string.as_ptr()) }
+    }
+
+    fn set_out(set: &Set<Self>) -> *mut c_char {
+        unsafe { meos_sys::tstzset_out(set.inner()) }
+    }
+
+    fn set_make(values: &[Self]) -> *mut meos_sys::Set {
+        let timestamps: Vec<_> = values.iter().map(to_meos_timestamp).collect();
+        unsafe { meos_sys::tstzset_make(timestamps.as_ptr(), timestamps.len() as i32) }
+    }
+
+    fn set_value_n(set: &Set<Self>, n: i32) -> Self {
+        let mut value = 0;
+        unsafe { meos_sys::tstzset_value_n(set.inner(), n, ptr::addr_of_mut!(value)) };
+        from_meos_timestamp(value)
+    }
+
+    fn set_contains(set: &Set<Self>, value: &Self) -> bool {
+        unsafe { meos_sys::contains_set_timestamptz(set.inner(), to_meos_timestamp(value)) }
+    }
+}
+
+impl SpanSetElement for DateTime<Utc> {
+    type SpanSetType = TsTzSpanSet;
+}
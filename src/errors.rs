@@ -1,2 +1,123 @@
+use std::fmt;
+
+use crate::WKBVariant;
+
 #[derive(Debug, PartialEq, Eq)]
 pub struct ParseError;
+
+/// Error returned when MEOS fails to parse the textual representation of a temporal object.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TemporalParseError {
+    /// Byte offset of the input where parsing failed, when it can be determined.
+    pub position: Option<usize>,
+    /// Error message reported by MEOS.
+    pub message: String,
+}
+
+impl TemporalParseError {
+    /// Builds the error for `input`, locating the failure from the MEOS message when it ends
+    /// with the unparsed remainder of the input, as most MEOS input functions report it.
+    pub(crate) fn new(input: &str, message: String) -> Self {
+        let position = message
+            .rsplit_once(": ")
+            .map(|(_, remainder)| remainder)
+            .filter(|remainder| !remainder.is_empty() && input.ends_with(remainder))
+            .map(|remainder| input.len() - remainder.len());
+        Self { position, message }
+    }
+}
+
+impl fmt::Display for TemporalParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.position {
+            Some(position) => write!(f, "{} (at byte {})", self.message, position),
+            None => f.write_str(&self.message),
+        }
+    }
+}
+
+impl std::error::Error for TemporalParseError {}
+
+/// Error returned when MEOS rejects an operation, or when its arguments are known to be
+/// rejected by MEOS before calling it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MeosError {
+    /// Description of the error, as reported by MEOS when it comes from it.
+    pub message: String,
+}
+
+impl fmt::Display for MeosError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.message)
+    }
+}
+
+impl std::error::Error for MeosError {}
+
+/// Error returned when an operation is only defined for a specific SRID.
+#[derive(Debug, PartialEq, Eq)]
+pub struct SridError {
+    pub expected: i32,
+    pub found: i32,
+}
+
+/// Error returned when materializing a collection would exceed the requested number of values.
+#[derive(Debug, PartialEq, Eq)]
+pub struct TooLargeError {
+    /// Number of values the collection holds.
+    pub count: u64,
+    /// Maximum number of values that was allowed.
+    pub limit: usize,
+}
+
+/// Error returned when the bounds given to build a span do not define a valid span.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SpanBoundsError {
+    /// A bound is NaN or infinite.
+    NonFinite,
+    /// The lower bound is greater than the upper bound.
+    LowerAboveUpper,
+    /// Both bounds are equal but at least one of them is exclusive, so the span has no value.
+    Empty,
+}
+
+impl fmt::Display for SpanBoundsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            SpanBoundsError::NonFinite => "Span bounds must be finite",
+            SpanBoundsError::LowerAboveUpper => "Span lower bound is greater than its upper bound",
+            SpanBoundsError::Empty => "Span bounds are equal but not both inclusive",
+        })
+    }
+}
+
+impl std::error::Error for SpanBoundsError {}
+
+/// Error returned when Well-Known Binary (WKB) bytes cannot be decoded.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum WkbError {
+    /// The byte order flag of the bytes is not the expected one, so the rest of the bytes
+    /// would be read with the wrong endianness.
+    EndiannessMismatch {
+        /// The expected byte order, `WKBVariant::NDR` or `WKBVariant::XDR`.
+        expected: WKBVariant,
+        /// The byte order flagged in the bytes.
+        found: WKBVariant,
+    },
+    /// The bytes are not valid WKB, with the error message reported by MEOS if any.
+    Invalid(String),
+}
+
+impl fmt::Display for WkbError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            WkbError::EndiannessMismatch { expected, found } => write!(
+                f,
+                "WKB endianness mismatch: expected {expected:?}, found {found:?}"
+            ),
+            WkbError::Invalid(message) => write!(f, "Invalid WKB: {message}"),
+        }
+    }
+}
+
+impl std::error::Error for WkbError {}
@@ -1,6 +1,7 @@
 #![allow(refining_impl_trait)]
 #![allow(clippy::non_canonical_partial_ord_impl)]
 use std::{
+    cell::{Cell, RefCell},
     ffi::{c_void, CStr, CString},
     fmt::Debug,
     sync::Once,
@@ -17,7 +18,7 @@ pub mod collections;
 pub use collections::base::{collection::Collection, span::Span, span_set::SpanSet};
 
 pub mod errors;
-pub use errors::ParseError;
+pub use errors::{ParseError, WkbError};
 
 pub mod temporal;
 pub use temporal::{
@@ -29,6 +30,8 @@ pub use temporal::{
 };
 
 pub(crate) mod utils;
+#[cfg(feature = "unsafe-api")]
+pub use utils::{duration_to_interval, interval_to_duration};
 
 static START: Once = Once::new();
 
@@ -42,9 +45,31 @@ pub trait BoundingBox: Collection {}
 
 impl<T> BoundingBox for T where T: MeosBox {}
 
+thread_local! {
+    /// Whether MEOS errors raised on this thread should be recorded instead of panicking.
+    static CAPTURE_ERRORS: Cell<bool> = const { Cell::new(false) };
+    /// The last MEOS error message recorded while `CAPTURE_ERRORS` was set.
+    static CAPTURED_ERROR: RefCell<Option<String>> = const { RefCell::new(None) };
+}
+
 unsafe extern "C" fn error_handler(_error_level: i32, _error_code: i32, message: *const i8) {
     let message = CStr::from_ptr(message).to_str().unwrap();
-    panic!("{}", message);
+    if CAPTURE_ERRORS.get() {
+        CAPTURED_ERROR.replace(Some(message.to_owned()));
+    } else {
+        panic!("{}", message);
+    }
+}
+
+/// Runs `f`, recording any MEOS error it raises instead of panicking.
+///
+/// MEOS functions return a null pointer after reporting an error, so `f` is expected to
+/// return a value the caller can discard when an error message is returned.
+pub(crate) fn capture_meos_error<T>(f: impl FnOnce() -> T) -> (T, Option<String>) {
+    let previous = CAPTURE_ERRORS.replace(true);
+    let result = f();
+    CAPTURE_ERRORS.set(previous);
+    (result, CAPTURED_ERROR.take())
 }
 
 /// Initializes the underlying MEOS platform.
@@ -69,6 +94,107 @@ pub fn meos_initialize(tz: &str) {
     });
 }
 
+/// Returns the version of the MEOS library this crate is built against.
+///
+/// MEOS 1.1 does not export a function reporting its own version, so this is the version
+/// pkg-config reported when `meos_sys` was built, or the version of its bindings when the
+/// library was found through `MEOS_LIB_DIR`.
+///
+/// ## Example
+/// ```
+/// assert!(meos::version().starts_with("1."));
+/// ```
+pub fn version() -> &'static str {
+    meos_sys::LIBRARY_VERSION.unwrap_or(meos_sys::BINDINGS_VERSION)
+}
+
+/// Returns whether this build of the crate supports the feature `name`.
+///
+/// Every MEOS 1.1 build ships the same types, so features are the cargo features this crate
+/// was compiled with: `"geos"`, `"serde"`, `"num-traits"` and `"unsafe-api"`. `"geometry"`
+/// and `"geography"` are accepted as aliases of `"geos"`, which enables the temporal point
+/// types. Any other name returns `false`.
+///
+/// ## Arguments
+/// * `name` - The name of the feature, case insensitive.
+///
+/// ## Example
+/// ```
+/// assert!(!meos::has_feature("unknown"));
+/// ```
+pub fn has_feature(name: &str) -> bool {
+    let name = name.to_lowercase();
+    let name = match name.as_str() {
+        "geometry" | "geography" => "geos",
+        other => other,
+    };
+    [
+        ("geos", cfg!(feature = "geos")),
+        ("serde", cfg!(feature = "serde")),
+        ("num-traits", cfg!(feature = "num-traits")),
+        ("unsafe-api", cfg!(feature = "unsafe-api")),
+    ]
+    .contains(&(name, true))
+}
+
+/// Returns whether `temporal` is unchanged after being serialized to WKB and to MF-JSON and
+/// parsed back, e.g. to check a custom pipeline in tests.
+///
+/// Floating point values are written to MF-JSON with 15 decimal digits, so values needing
+/// more precision do not round-trip.
+///
+/// ## Arguments
+/// * `temporal` - The temporal object to serialize.
+///
+/// ## Returns
+/// `true` if both decoded objects are equal to `temporal`, `false` if any serialization,
+/// parsing or comparison failed.
+///
+/// MEOS Functions:
+///     `temporal_as_wkb`, `temporal_from_wkb`, `temporal_as_mfjson`, `<type>_from_mfjson`,
+///     `temporal_eq`
+pub fn verify_roundtrip(temporal: &impl Temporal) -> bool {
+    roundtrip(temporal, 15)
+}
+
+fn roundtrip(temporal: &impl Temporal, precision: i32) -> bool {
+    let inner = temporal.inner();
+    let from_mfjson: unsafe extern "C" fn(*const std::os::raw::c_char) -> *mut meos_sys::Temporal =
+        match unsafe { (*inner).temptype } as meos_sys::meosType {
+            meos_sys::meosType_T_TBOOL => meos_sys::tbool_from_mfjson,
+            meos_sys::meosType_T_TINT => meos_sys::tint_from_mfjson,
+            meos_sys::meosType_T_TFLOAT => meos_sys::tfloat_from_mfjson,
+            meos_sys::meosType_T_TTEXT => meos_sys::ttext_from_mfjson,
+            meos_sys::meosType_T_TGEOMPOINT => meos_sys::tgeompoint_from_mfjson,
+            meos_sys::meosType_T_TGEOGPOINT => meos_sys::tgeogpoint_from_mfjson,
+            _ => return false,
+        };
+    let (decoded, error) = capture_meos_error(|| unsafe {
+        let mut size = 0;
+        let wkb = meos_sys::temporal_as_wkb(inner, WKBVariant::Extended.into(), &mut size);
+        let from_wkb = meos_sys::temporal_from_wkb(wkb, size);
+        libc::free(wkb as *mut c_void);
+        let mfjson = meos_sys::temporal_as_mfjson(
+            inner,
+            false,
+            JSONCVariant::Plain as i32,
+            precision,
+            std::ptr::null(),
+        );
+        let from_mfjson = from_mfjson(mfjson);
+        libc::free(mfjson as *mut c_void);
+        [from_wkb, from_mfjson]
+    });
+    let mut equal = error.is_none();
+    for decoded in decoded {
+        equal &= !decoded.is_null() && unsafe { meos_sys::temporal_eq(inner, decoded) };
+        if !decoded.is_null() {
+            unsafe { libc::free(decoded as *mut c_void) };
+        }
+    }
+    equal
+}
+
 fn factory<T: MeosEnum>(temporal: *mut meos_sys::Temporal) -> T {
     let temporal_type: TemporalSubtype = unsafe { (temporal.read().subtype as u32).into() };
     match temporal_type {
@@ -116,12 +242,19 @@ pub trait MeosEnum: Debug + Sized + Temporal {
 
     /// Creates a temporal object from an MF-JSON string.
     ///
+    /// MF-JSON is the only JSON format supported for temporal objects, as MEOS has no GeoJSON
+    /// reader for them.
+    ///
     /// ## Arguments
     /// * `mfjson` - The MF-JSON string.
     ///
     /// ## Returns
-    /// A temporal object.
-    fn from_mfjson(mfjson: &str) -> Self;
+    /// A temporal object, or a `TemporalParseError` if `mfjson` is not a valid MF-JSON
+    /// representation of this temporal type.
+    ///
+    /// MEOS Functions:
+    ///     `<type>_from_mfjson`
+    fn from_mfjson(mfjson: &str) -> Result<Self, errors::TemporalParseError>;
 
     /// Creates a temporal object from Well-Known Binary (WKB) bytes.
     ///
@@ -134,6 +267,52 @@ pub trait MeosEnum: Debug + Sized + Temporal {
         factory::<Self>(unsafe { meos_sys::temporal_from_wkb(wkb.as_ptr(), wkb.len()) })
     }
 
+    /// Creates a temporal object from Well-Known Binary (WKB) bytes, checking that they are
+    /// encoded with the byte order of `variant`.
+    ///
+    /// Unlike `from_wkb`, bytes flagged with another byte order or that MEOS cannot decode
+    /// return an error instead of a wrong value or a panic.
+    ///
+    /// ## Arguments
+    /// * `wkb` - The WKB bytes.
+    /// * `variant` - The expected byte order, `WKBVariant::XDR` for big endian and
+    ///   little endian otherwise.
+    ///
+    /// ## Returns
+    /// A temporal object, or a `WkbError` if the bytes could not be decoded.
+    ///
+    /// MEOS Functions:
+    ///     `temporal_from_wkb`
+    fn try_from_wkb(wkb: &[u8], variant: WKBVariant) -> Result<Self, WkbError> {
+        let expected = if variant.contains(WKBVariant::XDR) {
+            WKBVariant::XDR
+        } else {
+            WKBVariant::NDR
+        };
+        let found = match wkb.first() {
+            Some(0) => WKBVariant::XDR,
+            Some(1) => WKBVariant::NDR,
+            Some(flag) => return Err(WkbError::Invalid(format!("Unknown byte order {flag}"))),
+            None => return Err(WkbError::Invalid("Empty input".to_owned())),
+        };
+        if found != expected {
+            return Err(WkbError::EndiannessMismatch { expected, found });
+        }
+        let (inner, error) =
+            capture_meos_error(|| unsafe { meos_sys::temporal_from_wkb(wkb.as_ptr(), wkb.len()) });
+        match error {
+            None if !inner.is_null() => Ok(factory::<Self>(inner)),
+            error => {
+                if !inner.is_null() {
+                    unsafe { libc::free(inner as *mut c_void) };
+                }
+                Err(WkbError::Invalid(
+                    error.unwrap_or_else(|| "Could not decode temporal".to_owned()),
+                ))
+            }
+        }
+    }
+
     /// Creates a temporal object from a hex-encoded WKB string.
     ///
     /// ## Arguments
@@ -200,24 +379,52 @@ pub trait MeosEnum: Debug + Sized + Temporal {
     ///
     /// ## Returns
     /// The temporal object as WKB bytes.
-    fn as_wkb(&self, variant: WKBVariant) -> &[u8] {
+    fn as_wkb(&self, variant: WKBVariant) -> Vec<u8> {
         unsafe {
             let mut size: usize = 0;
             let ptr = meos_sys::temporal_as_wkb(self.inner(), variant.into(), &mut size);
-            std::slice::from_raw_parts(ptr, size)
+            let bytes = std::slice::from_raw_parts(ptr, size).to_vec();
+            libc::free(ptr as *mut c_void);
+            bytes
         }
     }
 
     /// Returns the temporal object as a hex-encoded WKB string.
     ///
     /// ## Returns
-    /// The temporal object as a hex-encoded WKB bytes.
-    fn as_hexwkb(&self, variant: WKBVariant) -> &[u8] {
+    /// The temporal object as a hex-encoded WKB string.
+    fn as_hexwkb(&self, variant: WKBVariant) -> String {
         unsafe {
             let mut size: usize = 0;
             let hexwkb_ptr = meos_sys::temporal_as_hexwkb(self.inner(), variant.into(), &mut size);
+            let hexwkb = CStr::from_ptr(hexwkb_ptr).to_str().unwrap().to_owned();
+            libc::free(hexwkb_ptr as *mut c_void);
+            hexwkb
+        }
+    }
+}
 
-            CStr::from_ptr(hexwkb_ptr).to_bytes()
+/// Parses `mfjson` with the MEOS reader `parse`, returning the MEOS error instead of panicking
+/// when the input is invalid.
+fn parse_mfjson<T: MeosEnum>(
+    mfjson: &str,
+    parse: unsafe extern "C" fn(*const std::os::raw::c_char) -> *mut meos_sys::Temporal,
+) -> Result<T, errors::TemporalParseError> {
+    let string = CString::new(mfjson).map_err(|error| errors::TemporalParseError {
+        position: Some(error.nul_position()),
+        message: "Unexpected nul character".to_owned(),
+    })?;
+    let (inner, error) = capture_meos_error(|| unsafe { parse(string.as_ptr()) });
+    match error {
+        None if !inner.is_null() => Ok(factory::<T>(inner)),
+        error => {
+            if !inner.is_null() {
+                unsafe { libc::free(inner as *mut c_void) };
+            }
+            Err(errors::TemporalParseError::new(
+                mfjson,
+                error.unwrap_or_else(|| "Could not parse MF-JSON".to_owned()),
+            ))
         }
     }
 }
@@ -226,16 +433,126 @@ macro_rules! impl_from_str {
     ($type:ty) => {
         paste::paste! {
         impl FromStr for $type {
-            type Err = ParseError;
+            type Err = crate::errors::TemporalParseError;
 
             fn from_str(s: &str) -> Result<Self, Self::Err> {
-                CString::new(s).map_err(|_| ParseError).map(|string| {
-                    let inner = unsafe { meos_sys::[<$type:lower _in>](string.as_ptr()) };
-                    factory::<Self>(inner)
-                })
+                let string = CString::new(s).map_err(|error| crate::errors::TemporalParseError {
+                    position: Some(error.nul_position()),
+                    message: "Unexpected nul character".to_owned(),
+                })?;
+                let (inner, error) = crate::capture_meos_error(|| unsafe {
+                    meos_sys::[<$type:lower _in>](string.as_ptr())
+                });
+                match error {
+                    None if !inner.is_null() => Ok(factory::<Self>(inner)),
+                    error => {
+                        if !inner.is_null() {
+                            unsafe { libc::free(inner as *mut std::ffi::c_void) };
+                        }
+                        Err(crate::errors::TemporalParseError::new(
+                            s,
+                            error.unwrap_or_else(|| format!("Could not parse {}", stringify!($type))),
+                        ))
+                    }
+                }
             }
         }}
     };
 }
 
 pub(crate) use impl_from_str;
+
+/// Implements `serde::Serialize` and `serde::Deserialize` for `$type` when the `serde` feature
+/// is enabled, through its textual MEOS representation.
+///
+/// `$out` converts a `&$type` into the string allocated by the MEOS output function, which is
+/// freed after serializing it. Deserialization goes through the `FromStr` implementation.
+macro_rules! impl_serde {
+    ($type:ty, $out:expr) => {
+        #[cfg(feature = "serde")]
+        impl serde::Serialize for $type {
+            fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                let out: fn(&$type) -> *mut std::ffi::c_char = $out;
+                let out_str = out(self);
+                let string = unsafe { std::ffi::CStr::from_ptr(out_str) }
+                    .to_string_lossy()
+                    .into_owned();
+                unsafe { libc::free(out_str as *mut std::ffi::c_void) };
+                serializer.serialize_str(&string)
+            }
+        }
+
+        #[cfg(feature = "serde")]
+        impl<'de> serde::Deserialize<'de> for $type {
+            fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                let string = <String as serde::Deserialize>::deserialize(deserializer)?;
+                string.parse().map_err(|error| {
+                    serde::de::Error::custom(format!(
+                        "Invalid {} {string:?}: {error:?}",
+                        stringify!($type)
+                    ))
+                })
+            }
+        }
+    };
+}
+
+pub(crate) use impl_serde;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn version_from_meos_sys() {
+        assert!(version().starts_with("1."));
+    }
+
+    #[test]
+    fn features_from_cargo() {
+        assert_eq!(has_feature("geos"), cfg!(feature = "geos"));
+        assert_eq!(has_feature("Geography"), cfg!(feature = "geos"));
+        assert_eq!(has_feature("serde"), cfg!(feature = "serde"));
+        assert!(!has_feature("unknown"));
+    }
+
+    #[test]
+    fn verify_roundtrip_tfloat() {
+        meos_initialize("UTC");
+        let tfloat: TFloatSequence = "[1.5@2018-01-01 08:00:00+00, 2.25@2018-01-01 08:10:00+00]"
+            .parse::<TFloat>()
+            .unwrap()
+            .try_into()
+            .unwrap();
+        assert!(verify_roundtrip(&tfloat));
+
+        let precise: TFloatSequence = "[1.2345@2018-01-01 08:00:00+00, 2@2018-01-01 08:10:00+00]"
+            .parse::<TFloat>()
+            .unwrap()
+            .try_into()
+            .unwrap();
+        assert!(verify_roundtrip(&precise));
+        // Writing a single decimal digit to MF-JSON loses 1.2345
+        assert!(!roundtrip(&precise, 1));
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn serde_roundtrip_collections() {
+        use collections::{datetime::tstz_span_set::TsTzSpanSet, number::float_span::FloatSpan};
+        use std::str::FromStr;
+
+        meos_initialize("UTC");
+        let span: FloatSpan = (1.123456789..2.5).into();
+        let json = serde_json::to_string(&span).unwrap();
+        assert_eq!(serde_json::from_str::<FloatSpan>(&json).unwrap(), span);
+
+        let span_set =
+            TsTzSpanSet::from_str("{[2020-01-01, 2020-01-02], [2020-01-05, 2020-01-06)}").unwrap();
+        let json = serde_json::to_string(&span_set).unwrap();
+        assert_eq!(
+            serde_json::from_str::<TsTzSpanSet>(&json).unwrap(),
+            span_set
+        );
+    }
+}
@@ -0,0 +1,214 @@
+use std::ffi::{c_void, CString};
+
+#[cfg(feature = "geos")]
+use super::point::{tgeogpoint::TGeogPoint, tgeompoint::TGeomPoint};
+use super::{
+    number::{tfloat::TFloat, tint::TInt},
+    tbool::TBool,
+    ttext::TText,
+};
+use crate::{capture_meos_error, errors::TemporalParseError, factory};
+
+/// A temporal object whose base type is only known at runtime.
+#[derive(Debug, Clone, PartialEq)]
+pub enum AnyTemporal {
+    Bool(TBool),
+    Int(TInt),
+    Float(TFloat),
+    Text(TText),
+    #[cfg(feature = "geos")]
+    GeomPoint(TGeomPoint),
+    #[cfg(feature = "geos")]
+    GeogPoint(TGeogPoint),
+}
+
+impl AnyTemporal {
+    /// Parses a temporal object, detecting its encoding from the input.
+    ///
+    /// Inputs that look like a JSON object are read as MF-JSON, inputs made only of hexadecimal
+    /// digits are read as hex-encoded WKB, and anything else is read as the MEOS text format.
+    ///
+    /// MF-JSON and WKB carry the temporal type. For the text format it is guessed from the
+    /// first value: `t`/`f` is a `TBool`, a quoted string a `TText`, a `POINT` a `TGeomPoint`,
+    /// and a number a `TFloat` if it has a decimal point or an exponent, or a `TInt` otherwise.
+    /// The text format does not say whether points are geodetic, so a `TGeogPoint` is only
+    /// detected from MF-JSON or WKB.
+    ///
+    /// ## Arguments
+    /// * `s` - The encoded temporal object.
+    ///
+    /// ## Returns
+    /// The parsed temporal object, or a `TemporalParseError` if it could not be parsed.
+    ///
+    /// MEOS Functions:
+    ///     `<type>_from_mfjson`, `temporal_from_hexwkb`, `<type>_in`
+    pub fn parse_auto(s: &str) -> Result<Self, TemporalParseError> {
+        let trimmed = s.trim();
+        if trimmed.starts_with('{') && trimmed.contains("\"type\"") {
+            Self::from_mfjson(trimmed)
+        } else if !trimmed.is_empty() && trimmed.chars().all(|c| c.is_ascii_hexdigit()) {
+            Self::from_hexwkb(trimmed)
+        } else {
+            Self::from_text(trimmed)
+        }
+    }
+
+    fn from_mfjson(mfjson: &str) -> Result<Self, TemporalParseError> {
+        let parse: unsafe extern "C" fn(*const std::os::raw::c_char) -> *mut meos_sys::Temporal =
+            match mfjson_type(mfjson) {
+                Some("MovingBoolean") => meos_sys::tbool_from_mfjson,
+                Some("MovingInteger") => meos_sys::tint_from_mfjson,
+                Some("MovingFloat") => meos_sys::tfloat_from_mfjson,
+                Some("MovingText") => meos_sys::ttext_from_mfjson,
+                #[cfg(feature = "geos")]
+                Some("MovingPoint" | "MovingGeomPoint") => meos_sys::tgeompoint_from_mfjson,
+                #[cfg(feature = "geos")]
+                Some("MovingGeogPoint") => meos_sys::tgeogpoint_from_mfjson,
+                other => {
+                    return Err(TemporalParseError {
+                        position: None,
+                        message: format!("Unknown MF-JSON type {other:?}"),
+                    })
+                }
+            };
+        let string = CString::new(mfjson).map_err(|error| TemporalParseError {
+            position: Some(error.nul_position()),
+            message: "Unexpected nul character".to_owned(),
+        })?;
+        let (inner, error) = capture_meos_error(|| unsafe { parse(string.as_ptr()) });
+        Self::from_parsed(mfjson, inner, error)
+    }
+
+    fn from_hexwkb(hexwkb: &str) -> Result<Self, TemporalParseError> {
+        let string = CString::new(hexwkb).unwrap();
+        let (inner, error) =
+            capture_meos_error(|| unsafe { meos_sys::temporal_from_hexwkb(string.as_ptr()) });
+        Self::from_parsed(hexwkb, inner, error)
+    }
+
+    fn from_text(text: &str) -> Result<Self, TemporalParseError> {
+        let mut body = text;
+        while let Some((_, rest)) = body
+            .split_once(';')
+            .filter(|(prefix, _)| prefix.contains('=') && !prefix.contains('"'))
+        {
+            body = rest;
+        }
+        let first_value = body
+            .trim_start_matches(|c: char| "{[( ".contains(c))
+            .split('@')
+            .next()
+            .unwrap_or_default()
+            .trim();
+        let lowercase = first_value.to_lowercase();
+        if first_value.starts_with('"') {
+            return text.parse().map(AnyTemporal::Text);
+        }
+        #[cfg(feature = "geos")]
+        if lowercase.contains("point") {
+            return text.parse().map(AnyTemporal::GeomPoint);
+        }
+        if matches!(lowercase.as_str(), "t" | "f" | "true" | "false") {
+            text.parse().map(AnyTemporal::Bool)
+        } else if lowercase.contains(['.', 'e', 'n']) {
+            text.parse().map(AnyTemporal::Float)
+        } else {
+            text.parse().map(AnyTemporal::Int)
+        }
+    }
+
+    fn from_parsed(
+        input: &str,
+        inner: *mut meos_sys::Temporal,
+        error: Option<String>,
+    ) -> Result<Self, TemporalParseError> {
+        let result = match error {
+            None if !inner.is_null() => Self::from_inner(inner),
+            _ => None,
+        };
+        result.ok_or_else(|| {
+            if !inner.is_null() {
+                unsafe { libc::free(inner as *mut c_void) };
+            }
+            TemporalParseError::new(
+                input,
+                error.unwrap_or_else(|| "Could not parse temporal".to_owned()),
+            )
+        })
+    }
+
+    fn from_inner(inner: *mut meos_sys::Temporal) -> Option<Self> {
+        let temptype = unsafe { (*inner).temptype } as meos_sys::meosType;
+        match temptype {
+            meos_sys::meosType_T_TBOOL => Some(AnyTemporal::Bool(factory(inner))),
+            meos_sys::meosType_T_TINT => Some(AnyTemporal::Int(factory(inner))),
+            meos_sys::meosType_T_TFLOAT => Some(AnyTemporal::Float(factory(inner))),
+            meos_sys::meosType_T_TTEXT => Some(AnyTemporal::Text(factory(inner))),
+            #[cfg(feature = "geos")]
+            meos_sys::meosType_T_TGEOMPOINT => Some(AnyTemporal::GeomPoint(factory(inner))),
+            #[cfg(feature = "geos")]
+            meos_sys::meosType_T_TGEOGPOINT => Some(AnyTemporal::GeogPoint(factory(inner))),
+            _ => None,
+        }
+    }
+}
+
+/// Returns the value of the first `"type"` member of an MF-JSON document.
+fn mfjson_type(mfjson: &str) -> Option<&str> {
+    let (_, rest) = mfjson.split_once("\"type\"")?;
+    let rest = rest.trim_start().strip_prefix(':')?.trim_start();
+    let rest = rest.strip_prefix('"')?;
+    rest.split_once('"').map(|(value, _)| value)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{meos_initialize, temporal::JSONCVariant, MeosEnum, WKBVariant};
+
+    use super::*;
+
+    #[test]
+    fn parse_auto_tfloat() {
+        meos_initialize("UTC");
+        let text = "[1.5@2018-01-01 08:00:00+00, 2.5@2018-01-01 09:00:00+00]";
+        let tfloat: TFloat = text.parse().unwrap();
+        let expected = AnyTemporal::Float(tfloat.clone());
+
+        let mfjson = tfloat.as_mfjson(false, JSONCVariant::Plain, 5, "");
+        let hexwkb = tfloat.as_hexwkb(WKBVariant::Extended);
+
+        assert_eq!(AnyTemporal::parse_auto(text).unwrap(), expected);
+        assert_eq!(AnyTemporal::parse_auto(&mfjson).unwrap(), expected);
+        assert_eq!(AnyTemporal::parse_auto(&hexwkb).unwrap(), expected);
+    }
+
+    #[test]
+    fn parse_auto_ttext_containing_point() {
+        meos_initialize("UTC");
+        let text = r#"{"checkpoint"@2020-01-01 00:00:00+00, "Point B"@2020-01-02 00:00:00+00}"#;
+        let ttext: TText = text.parse().unwrap();
+
+        assert_eq!(
+            AnyTemporal::parse_auto(text).unwrap(),
+            AnyTemporal::Text(ttext)
+        );
+    }
+
+    #[cfg(feature = "geos")]
+    #[test]
+    fn parse_auto_tpoint() {
+        meos_initialize("UTC");
+        let text = "[SRID=4326;POINT(1 1)@2020-01-01 00:00:00+00, SRID=4326;POINT(2 2)@2020-01-02 00:00:00+00]";
+        let tgeompoint: TGeomPoint = text.parse().unwrap();
+        let tgeogpoint: TGeogPoint = text.parse().unwrap();
+
+        assert_eq!(
+            AnyTemporal::parse_auto(text).unwrap(),
+            AnyTemporal::GeomPoint(tgeompoint)
+        );
+        assert_eq!(
+            AnyTemporal::parse_auto(&tgeogpoint.as_hexwkb(WKBVariant::Extended)).unwrap(),
+            AnyTemporal::GeogPoint(tgeogpoint)
+        );
+    }
+}
@@ -0,0 +1,148 @@
+//! Builders of synthetic temporal objects, meant for tests and benchmarks.
+//!
+//! Every generator is deterministic: the same arguments always produce the same object.
+
+use std::f64::consts::TAU;
+
+use chrono::{DateTime, TimeDelta, Utc};
+
+use super::number::{
+    tfloat::{TFloatInstant, TFloatSequence},
+    tint::TIntSequence,
+};
+
+/// Samples a sine wave between `start` and `end`, both included, every `step`.
+///
+/// The wave is `amplitude * sin(2π * (t - start) / period)`, so it starts at zero and takes
+/// values between `-amplitude` and `amplitude`.
+///
+/// ## Arguments
+/// * `start` - Timestamp of the first sample.
+/// * `end` - Latest timestamp of a sample.
+/// * `step` - Time between consecutive samples.
+/// * `amplitude` - Peak value of the wave.
+/// * `period` - Time it takes the wave to complete a cycle.
+///
+/// ## Returns
+/// A linear `TFloatSequence` with one instant per sample.
+///
+/// ## Panics
+/// If `step` is not positive or `end` is before `start`.
+pub fn sine_tfloat(
+    start: DateTime<Utc>,
+    end: DateTime<Utc>,
+    step: TimeDelta,
+    amplitude: f64,
+    period: TimeDelta,
+) -> TFloatSequence {
+    assert!(step > TimeDelta::zero(), "Step must be positive");
+    assert!(start <= end, "End must not be before start");
+    let period = seconds(period);
+    std::iter::successors(Some(start), |&timestamp| Some(timestamp + step))
+        .take_while(|&timestamp| timestamp <= end)
+        .map(|timestamp| {
+            let phase = TAU * seconds(timestamp - start) / period;
+            TFloatInstant::from((amplitude * phase.sin(), timestamp))
+        })
+        .collect()
+}
+
+/// Generates a random walk of `count` values, one every `step` from `start`.
+///
+/// The walk starts at `initial` and then moves one unit up or down at every sample. The moves
+/// are drawn from a pseudo-random generator seeded with `seed`, so the same seed always
+/// produces the same walk.
+///
+/// ## Arguments
+/// * `start` - Timestamp of the first sample.
+/// * `step` - Time between consecutive samples.
+/// * `count` - Number of samples.
+/// * `initial` - Value of the first sample.
+/// * `seed` - Seed of the pseudo-random generator.
+///
+/// ## Returns
+/// A stepwise `TIntSequence` with `count` instants.
+///
+/// ## Panics
+/// If `count` is zero.
+pub fn random_walk_tint(
+    start: DateTime<Utc>,
+    step: TimeDelta,
+    count: usize,
+    initial: i32,
+    seed: u64,
+) -> TIntSequence {
+    assert!(count > 0, "A sequence needs at least one instant");
+    let mut state = seed;
+    let mut value = initial;
+    (0..count)
+        .map(|i| {
+            if i > 0 {
+                value += if splitmix64(&mut state) & 1 == 0 {
+                    1
+                } else {
+                    -1
+                };
+            }
+            (value, start + step * i as i32)
+        })
+        .collect()
+}
+
+fn seconds(delta: TimeDelta) -> f64 {
+    delta.num_microseconds().unwrap_or(i64::MAX) as f64 / 1e6
+}
+
+/// SplitMix64, a small and fast generator that is good enough for test data.
+fn splitmix64(state: &mut u64) -> u64 {
+    *state = state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+    let mut z = *state;
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    z ^ (z >> 31)
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::TimeZone;
+
+    use super::*;
+    use crate::{
+        meos_initialize,
+        temporal::temporal::{OrderedTemporal, Temporal},
+    };
+
+    #[test]
+    fn sine_tfloat_range() {
+        meos_initialize("UTC");
+        let start = Utc.with_ymd_and_hms(2020, 1, 1, 0, 0, 0).unwrap();
+        let sine = sine_tfloat(
+            start,
+            start + TimeDelta::hours(1),
+            TimeDelta::minutes(1),
+            2.5,
+            TimeDelta::minutes(20),
+        );
+        assert_eq!(sine.num_instants(), 61);
+        assert!((sine.max_value() - 2.5).abs() < 1e-9);
+        assert!((sine.min_value() + 2.5).abs() < 1e-9);
+    }
+
+    #[test]
+    fn random_walk_tint_is_reproducible() {
+        meos_initialize("UTC");
+        let start = Utc.with_ymd_and_hms(2020, 1, 1, 0, 0, 0).unwrap();
+        let walk = random_walk_tint(start, TimeDelta::seconds(1), 100, 0, 42);
+        assert_eq!(walk.num_instants(), 100);
+        assert_eq!(
+            walk,
+            random_walk_tint(start, TimeDelta::seconds(1), 100, 0, 42)
+        );
+        let values: Vec<i32> = walk
+            .timestamps()
+            .into_iter()
+            .map(|timestamp| walk.value_at_timestamp(timestamp).unwrap())
+            .collect();
+        assert!(values.windows(2).all(|pair| (pair[1] - pair[0]).abs() == 1));
+    }
+}
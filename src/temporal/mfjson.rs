@@ -0,0 +1,48 @@
+//! Serializes temporal objects as MF-JSON strings instead of their textual MEOS representation,
+//! to use with `#[serde(with = "meos::temporal::mfjson")]`.
+//!
+//! ## Example
+//! ```
+//! # use meos::temporal::number::tint::TInt;
+//! # use meos::meos_initialize;
+//! use serde::{Deserialize, Serialize};
+//! use std::str::FromStr;
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct Vehicle {
+//!     #[serde(with = "meos::temporal::mfjson")]
+//!     passengers: TInt,
+//! }
+//! # meos_initialize("UTC");
+//! let vehicle = Vehicle {
+//!     passengers: TInt::from_str("[1@2020-01-01, 3@2020-01-02]").unwrap(),
+//! };
+//! let json = serde_json::to_string(&vehicle).unwrap();
+//! assert!(json.contains("MovingInteger"));
+//! let parsed: Vehicle = serde_json::from_str(&json).unwrap();
+//! assert_eq!(parsed.passengers, vehicle.passengers);
+//! ```
+
+use serde::{de::Error, Deserialize, Deserializer, Serializer};
+
+use crate::{temporal::JSONCVariant, MeosEnum};
+
+/// Serializes `temporal` as an MF-JSON string, without bounding box nor SRS.
+///
+/// MEOS Functions:
+///     `temporal_as_mfjson`
+pub fn serialize<T: MeosEnum, S: Serializer>(
+    temporal: &T,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    serializer.serialize_str(&temporal.as_mfjson(false, JSONCVariant::Plain, 15, ""))
+}
+
+/// Deserializes a temporal object from an MF-JSON string.
+///
+/// MEOS Functions:
+///     `*_from_mfjson`
+pub fn deserialize<'de, T: MeosEnum, D: Deserializer<'de>>(deserializer: D) -> Result<T, D::Error> {
+    let mfjson = String::deserialize(deserializer)?;
+    T::from_mfjson(&mfjson).map_err(D::Error::custom)
+}
@@ -1,7 +1,12 @@
+pub mod any;
+pub mod generate;
 pub mod interpolation;
+#[cfg(feature = "serde")]
+pub mod mfjson;
 pub mod number;
 #[cfg(feature = "geos")]
 pub mod point;
+pub mod stream;
 pub mod tbool;
 #[allow(clippy::module_inception)]
 pub mod temporal;
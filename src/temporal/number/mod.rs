@@ -4,9 +4,27 @@ pub mod tnumber;
 
 #[cfg(test)]
 mod tests {
-    use crate::meos_initialize;
+    use crate::{
+        collections::{
+            datetime::{tstz_span::TsTzSpan, tstz_span_set::TsTzSpanSet},
+            number::{float_span::FloatSpan, float_span_set::FloatSpanSet},
+        },
+        meos_initialize,
+        temporal::{
+            interpolation::TInterpolation,
+            tbool::TBool,
+            temporal::{FillMethod, OrderedTemporal, Temporal},
+            tinstant::TInstant,
+            tsequence::{SequenceBuilder, TSequence},
+            tsequence_set::TSequenceSet,
+        },
+    };
 
     use super::*;
+    use chrono::{NaiveTime, TimeDelta, TimeZone, Utc};
+    use std::str::FromStr;
+    use tfloat::TFloatTrait;
+    use tnumber::TNumber;
 
     #[test]
     fn instant_tint() {
@@ -73,4 +91,1142 @@ mod tests {
             format!("SequenceSet({})", string.to_owned())
         );
     }
+
+    #[test]
+    fn same_values_ignores_interpolation() {
+        meos_initialize("UTC");
+        let stepwise: tint::TInt = "[1@2018-01-01 08:00:00+00, 2@2018-01-01 09:00:00+00]"
+            .parse()
+            .unwrap();
+        let discrete: tint::TInt = "{1@2018-01-01 08:00:00+00, 2@2018-01-01 09:00:00+00}"
+            .parse()
+            .unwrap();
+        assert!(stepwise.same_values(&discrete));
+        assert!(stepwise != discrete);
+    }
+
+    #[test]
+    fn chunks_tint() {
+        meos_initialize("UTC");
+        let string = format!(
+            "[{}]",
+            (0..10)
+                .map(|i| format!("{i}@2018-01-01 {:02}:00:00+00", i + 8))
+                .collect::<Vec<_>>()
+                .join(", ")
+        );
+        let result: tint::TInt = string.parse().unwrap();
+        let chunks = result.chunks(3);
+        // All but the last chunk end with the exclusive instant starting the next one
+        let sizes: Vec<i32> = chunks.iter().map(|chunk| chunk.num_instants()).collect();
+        assert_eq!(sizes, vec![5, 4, 3]);
+        assert_eq!(chunks[1].start_value(), 4);
+        assert_eq!(chunks[2].end_value(), 9);
+    }
+
+    #[test]
+    fn chunks_cover_duration_tfloat() {
+        meos_initialize("UTC");
+        let tfloat: tfloat::TFloat = "{[1@2018-01-01 08:00:00+00, 4@2018-01-01 11:00:00+00], \
+            [2@2018-01-01 12:00:00+00, 6@2018-01-01 14:00:00+00, 5@2018-01-01 15:00:00+00]}"
+            .parse()
+            .unwrap();
+        let chunks = tfloat.chunks(2);
+        assert_eq!(chunks.len(), 2);
+        let total: TimeDelta = chunks.iter().map(|chunk| chunk.duration(false)).sum();
+        assert_eq!(total, tfloat.duration(false));
+        assert_eq!(chunks[0].end_timestamp(), chunks[1].start_timestamp());
+        let integral: f64 = chunks.iter().map(|chunk| chunk.integral()).sum();
+        assert!((integral - tfloat.integral()).abs() < 1e-6 * tfloat.integral());
+    }
+
+    #[test]
+    fn constant_tfloat() {
+        meos_initialize("UTC");
+        let span: TsTzSpan = "[2018-01-01 00:00:00+00, 2018-01-02 00:00:00+00]"
+            .parse()
+            .unwrap();
+        let result = tfloat::TFloatSequence::constant(3.0, span.clone(), TInterpolation::Linear);
+        assert_eq!(result.start_value(), 3.0);
+        assert_eq!(result.end_value(), 3.0);
+        assert_eq!(result.timespan(), span);
+    }
+
+    #[test]
+    fn repeat_tfloat() {
+        meos_initialize("UTC");
+        let pattern: tfloat::TFloat = "[0@2018-01-01 00:00:00+00, 1@2018-01-02 00:00:00+00]"
+            .parse()
+            .unwrap();
+        let result = pattern.repeat(3, TimeDelta::zero()).unwrap();
+        assert_eq!(result.duration(false), TimeDelta::days(3));
+        assert_eq!(result.num_instants(), 6);
+        let day = |d| Utc.with_ymd_and_hms(2018, 1, d, 0, 0, 0).unwrap();
+        assert_eq!(result.value_at_timestamp(day(1)), Some(0.0));
+        assert_eq!(result.value_at_timestamp(day(2)), Some(0.0));
+        assert_eq!(result.value_at_timestamp(day(3)), Some(0.0));
+        assert_eq!(result.value_at_timestamp(day(4)), Some(1.0));
+    }
+
+    #[test]
+    fn repeat_instant_tfloat() {
+        meos_initialize("UTC");
+        let instant: tfloat::TFloat = "1@2018-01-01 00:00:00+00".parse().unwrap();
+        assert!(instant.repeat(2, TimeDelta::zero()).is_none());
+        assert!(instant.repeat(0, TimeDelta::hours(1)).is_none());
+        let result = instant.repeat(3, TimeDelta::hours(1)).unwrap();
+        assert_eq!(result.num_instants(), 3);
+        assert_eq!(
+            result.end_timestamp(),
+            Utc.with_ymd_and_hms(2018, 1, 1, 2, 0, 0).unwrap()
+        );
+    }
+
+    #[test]
+    fn first_and_last_time_at_value_tint() {
+        meos_initialize("UTC");
+        let result: tint::TInt = "{1@2018-01-01 08:00:00+00, 2@2018-01-01 09:00:00+00, 1@2018-01-01 10:00:00+00, 3@2018-01-01 11:00:00+00}"
+            .parse()
+            .unwrap();
+        let hour = |h| Utc.with_ymd_and_hms(2018, 1, 1, h, 0, 0).unwrap();
+        assert_eq!(result.first_time_at_value(&1), Some(hour(8)));
+        assert_eq!(result.last_time_at_value(&1), Some(hour(10)));
+        assert_eq!(result.first_time_at_value(&3), Some(hour(11)));
+        assert_eq!(result.first_time_at_value(&4), None);
+    }
+
+    #[test]
+    fn diff_tint() {
+        meos_initialize("UTC");
+        let original: tint::TInt = "[1@2018-01-01 08:00:00+00, 2@2018-01-01 09:00:00+00, 3@2018-01-01 10:00:00+00, 4@2018-01-01 11:00:00+00]"
+            .parse()
+            .unwrap();
+        let perturbed: tint::TInt = "[1@2018-01-01 08:00:00+00, 5@2018-01-01 09:00:00+00, 3@2018-01-01 10:00:00+00, 6@2018-01-01 11:00:00+00]"
+            .parse()
+            .unwrap();
+        let hour = |h| Utc.with_ymd_and_hms(2018, 1, 1, h, 0, 0).unwrap();
+        assert_eq!(
+            original.diff(&perturbed),
+            vec![(hour(9), 2, 5), (hour(11), 4, 6)]
+        );
+        assert!(original.diff(&original).is_empty());
+    }
+
+    #[test]
+    fn value_span_set_tfloat() {
+        meos_initialize("UTC");
+        let ramp: tfloat::TFloat = "[1@2018-01-01 08:00:00+00, 5@2018-01-01 12:00:00+00]"
+            .parse()
+            .unwrap();
+        assert_eq!(
+            ramp.value_span_set(),
+            FloatSpanSet::from_str("{[1, 5]}").unwrap()
+        );
+    }
+
+    #[test]
+    fn value_span_set_non_monotonic_tfloat() {
+        meos_initialize("UTC");
+        let rise_and_fall: tfloat::TFloat = "{[1@2018-01-01 08:00:00+00, 5@2018-01-01 09:00:00+00, 2@2018-01-01 10:00:00+00], [8@2018-01-01 11:00:00+00, 9@2018-01-01 12:00:00+00]}"
+            .parse()
+            .unwrap();
+        assert_eq!(
+            rise_and_fall.value_span_set(),
+            FloatSpanSet::from_str("{[1, 5], [8, 9]}").unwrap()
+        );
+    }
+
+    #[test]
+    fn at_values_in_span_tfloat() {
+        meos_initialize("UTC");
+        let ramp: tfloat::TFloat = "[1@2018-01-01 08:00:00+00, 5@2018-01-01 12:00:00+00]"
+            .parse()
+            .unwrap();
+        let fragment = ramp.at_values_in_span(&(2.0..=4.0).into()).unwrap();
+        assert_eq!(fragment.min_value(), 2.0);
+        assert_eq!(fragment.max_value(), 4.0);
+        assert_eq!(
+            fragment.timespan(),
+            TsTzSpan::from_str("[2018-01-01 09:00:00+00, 2018-01-01 11:00:00+00]").unwrap()
+        );
+        assert!(ramp.at_values_in_span(&(6.0..=7.0).into()).is_none());
+    }
+
+    #[test]
+    fn malformed_tint_reports_error() {
+        meos_initialize("UTC");
+        let error = "[1@2018-01-01 08:00:00+00, 2@not a date]"
+            .parse::<tint::TInt>()
+            .unwrap_err();
+        assert!(!error.message.is_empty());
+
+        let error = "1@2018-01-01\08:00:00+00"
+            .parse::<tint::TInt>()
+            .unwrap_err();
+        assert_eq!(error.position, Some(12));
+    }
+
+    #[test]
+    fn integral_over_tfloat() {
+        meos_initialize("UTC");
+        let power: tfloat::TFloat = "[3@2018-01-01 00:00:00+00, 3@2018-01-02 00:00:00+00]"
+            .parse()
+            .unwrap();
+        let billing_period: TsTzSpan = "[2018-01-01 10:00:00+00, 2018-01-01 12:00:00+00]"
+            .parse()
+            .unwrap();
+        let seconds = 2.0 * 3600.0;
+        assert_eq!(power.integral_over(&billing_period) / 1e6, 3.0 * seconds);
+
+        let outside: TsTzSpan = "[2018-02-01 00:00:00+00, 2018-02-02 00:00:00+00]"
+            .parse()
+            .unwrap();
+        assert_eq!(power.integral_over(&outside), 0.0);
+    }
+
+    #[test]
+    fn sort_tint_instants() {
+        meos_initialize("UTC");
+        let mut instants: Vec<tint::TIntInstant> = [
+            "5@2018-01-01 10:00:00+00",
+            "9@2018-01-01 08:00:00+00",
+            "1@2018-01-01 09:00:00+00",
+        ]
+        .iter()
+        .map(|string| tint::TInt::from_str(string).unwrap().try_into().unwrap())
+        .collect();
+        instants.sort();
+        let hours: Vec<_> = instants
+            .iter()
+            .map(|instant| instant.start_timestamp())
+            .collect();
+        let hour = |h| Utc.with_ymd_and_hms(2018, 1, 1, h, 0, 0).unwrap();
+        assert_eq!(hours, vec![hour(8), hour(9), hour(10)]);
+    }
+
+    #[test]
+    fn segment_rates_tfloat() {
+        meos_initialize("UTC");
+        let level: tfloat::TFloat =
+            "[0@2018-01-01 08:00:00+00, 60@2018-01-01 08:01:00+00, 30@2018-01-01 08:11:00+00]"
+                .parse()
+                .unwrap();
+        let rates = level.segment_rates();
+        assert_eq!(
+            rates,
+            vec![
+                (
+                    TsTzSpan::from_str("[2018-01-01 08:00:00+00, 2018-01-01 08:01:00+00)").unwrap(),
+                    1.0
+                ),
+                (
+                    TsTzSpan::from_str("[2018-01-01 08:01:00+00, 2018-01-01 08:11:00+00]").unwrap(),
+                    -0.05
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn coverage_fraction_tfloat() {
+        meos_initialize("UTC");
+        let readings: tfloat::TFloat = "[1@2018-01-01 08:00:00+00, 2@2018-01-01 09:00:00+00]"
+            .parse()
+            .unwrap();
+        let window: TsTzSpan = "[2018-01-01 08:00:00+00, 2018-01-01 10:00:00+00]"
+            .parse()
+            .unwrap();
+        assert!((readings.coverage_fraction(&window) - 0.5).abs() < 1e-9);
+
+        let outside: TsTzSpan = "[2018-01-02 08:00:00+00, 2018-01-02 10:00:00+00]"
+            .parse()
+            .unwrap();
+        assert_eq!(readings.coverage_fraction(&outside), 0.0);
+    }
+
+    #[test]
+    fn type_name_tfloat() {
+        meos_initialize("UTC");
+        let sequence: tfloat::TFloatSequence =
+            tfloat::TFloat::from_str("[1.5@2018-01-01 08:00:00+00, 2.5@2018-01-01 09:00:00+00]")
+                .unwrap()
+                .try_into()
+                .unwrap();
+        assert_eq!(sequence.type_name(), "tfloat");
+        assert_eq!(
+            tint::TInt::from_str("1@2018-01-01 08:00:00+00")
+                .unwrap()
+                .type_name(),
+            "tint"
+        );
+    }
+
+    #[test]
+    fn sequence_builder_tfloat() {
+        meos_initialize("UTC");
+        let start = Utc.with_ymd_and_hms(2018, 1, 1, 0, 0, 0).unwrap();
+        let instants: Vec<tfloat::TFloatInstant> = (0..10_000)
+            .map(|i| {
+                tfloat::TFloatInstant::from_value_and_timestamp(
+                    (i % 2) as f64,
+                    start + TimeDelta::seconds(i),
+                )
+            })
+            .collect();
+
+        let mut builder = SequenceBuilder::<tfloat::TFloatSequence>::new(TInterpolation::Linear);
+        for instant in &instants {
+            builder.push(instant);
+        }
+        assert_eq!(builder.len(), 10_000);
+        let built = builder.finish();
+
+        let batch = tfloat::TFloatSequence::new(&instants, TInterpolation::Linear);
+        assert_eq!(built.num_instants(), 10_000);
+        assert_eq!(built.instants(), batch.instants());
+    }
+
+    // Run under Valgrind in CI, which reports a double free if `instants` doesn't copy
+    #[test]
+    fn instants_outlive_temporal_tint() {
+        meos_initialize("UTC");
+        for string in [
+            "1@2019-07-08 10:00:00+00",
+            "[1@2019-07-08 10:00:00+00, 2@2019-07-08 11:00:00+00]",
+            "{[1@2019-07-08 10:00:00+00, 2@2019-07-08 11:00:00+00], [3@2019-07-08 12:00:00+00]}",
+        ] {
+            for _ in 0..100 {
+                let tint: tint::TInt = string.parse().unwrap();
+                let expected = tint.timestamps();
+                let instants = tint.instants();
+                drop(tint);
+                let timestamps: Vec<_> = instants.iter().map(|i| i.timestamp()).collect();
+                assert_eq!(timestamps, expected);
+                assert_eq!(instants[0].value(), 1);
+            }
+        }
+    }
+
+    #[test]
+    fn sampling_stats_tint() {
+        meos_initialize("UTC");
+        let regular: tint::TInt = "[1@2018-01-01 08:00:00+00, 2@2018-01-01 08:05:00+00, 3@2018-01-01 08:10:00+00, 4@2018-01-01 08:15:00+00]"
+            .parse()
+            .unwrap();
+        let stats = regular.sampling_stats();
+        assert_eq!(stats.count, 4);
+        assert_eq!(stats.min_gap, TimeDelta::minutes(5));
+        assert_eq!(stats.max_gap, stats.min_gap);
+        assert_eq!(stats.mean_gap, stats.min_gap);
+    }
+
+    #[test]
+    fn compact_tint() {
+        meos_initialize("UTC");
+        let tint: tint::TInt =
+            "[1@2018-01-01 08:00:00+00, 1@2018-01-01 08:10:00+00, 2@2018-01-01 08:20:00+00]"
+                .parse()
+                .unwrap();
+        let compacted = tint.compact();
+        assert!(compacted.num_instants() < tint.num_instants());
+        assert!(compacted.same_values(&tint));
+        for minutes in 0..20 {
+            let timestamp = Utc.with_ymd_and_hms(2018, 1, 1, 8, minutes, 0).unwrap();
+            assert_eq!(compacted.value_at_timestamp(timestamp), Some(1));
+        }
+    }
+
+    #[test]
+    fn expanding_bounds_tfloat() {
+        use crate::{boxes::r#box::Box as MeosBox, collections::base::collection::Collection};
+
+        meos_initialize("UTC");
+        let ramp = tfloat::TFloat::from_str(
+            "[0@2020-01-01 00:00:00+00, 1@2020-01-01 00:01:00+00, 2@2020-01-01 00:02:00+00, 3@2020-01-01 00:03:00+00]",
+        )
+        .unwrap();
+        let bounds = ramp.expanding_bounds();
+        assert_eq!(bounds.len(), 4);
+        assert_eq!(bounds[0].0, ramp.start_timestamp());
+        for pair in bounds.windows(2) {
+            assert!(pair[0].1.is_contained_in(&pair[1].1));
+        }
+        let (_, last) = bounds.last().unwrap();
+        assert_eq!(last.xmin(), Some(0.0));
+        assert_eq!(last.xmax(), Some(3.0));
+        assert_eq!(last.tstzspan(), ramp.timespan());
+    }
+
+    #[test]
+    fn try_from_wkb_endianness_mismatch() {
+        use crate::{MeosEnum, WKBVariant, WkbError};
+
+        meos_initialize("UTC");
+        let tint: tint::TInt = "[1@2018-01-01 08:00:00+00, 2@2018-01-01 08:10:00+00]"
+            .parse()
+            .unwrap();
+        let big_endian = tint.as_wkb(WKBVariant::XDR);
+        assert_eq!(
+            tint::TInt::try_from_wkb(&big_endian, WKBVariant::NDR),
+            Err(WkbError::EndiannessMismatch {
+                expected: WKBVariant::NDR,
+                found: WKBVariant::XDR,
+            })
+        );
+        assert_eq!(
+            tint::TInt::try_from_wkb(&big_endian, WKBVariant::XDR),
+            Ok(tint)
+        );
+    }
+
+    #[test]
+    fn nearest_instant_tfloat() {
+        meos_initialize("UTC");
+        let tfloat: tfloat::TFloat = "[1@2018-01-01 08:00:00+00, 5@2018-01-01 08:10:00+00]"
+            .parse()
+            .unwrap();
+        let between = Utc.with_ymd_and_hms(2018, 1, 1, 8, 3, 0).unwrap();
+        let nearest = tfloat.nearest_instant(between).unwrap();
+        assert_eq!(nearest.value(), 1.0);
+        assert_eq!(nearest.timestamp(), tfloat.start_timestamp());
+
+        let after = Utc.with_ymd_and_hms(2018, 1, 2, 0, 0, 0).unwrap();
+        assert_eq!(tfloat.nearest_instant(after).unwrap().value(), 5.0);
+    }
+
+    #[test]
+    fn find_instant_tfloat() {
+        meos_initialize("UTC");
+        let tfloat: tfloat::TFloat = "[1@2018-01-01 08:00:00+00, 4@2018-01-01 08:10:00+00, 7@2018-01-01 08:20:00+00, 9@2018-01-01 08:30:00+00]"
+            .parse()
+            .unwrap();
+        let found = tfloat.find_instant(|&value| value > 5.0).unwrap();
+        assert_eq!(found.value(), 7.0);
+        assert_eq!(
+            found.timestamp(),
+            Utc.with_ymd_and_hms(2018, 1, 1, 8, 20, 0).unwrap()
+        );
+        assert!(tfloat.find_instant(|&value| value > 10.0).is_none());
+    }
+
+    #[test]
+    fn distance_stats_tfloat() {
+        meos_initialize("UTC");
+        let lower: tfloat::TFloat = "[0@2018-01-01 08:00:00+00, 10@2018-01-01 09:00:00+00]"
+            .parse()
+            .unwrap();
+        let upper: tfloat::TFloat = "[3@2018-01-01 08:00:00+00, 13@2018-01-01 09:00:00+00]"
+            .parse()
+            .unwrap();
+        let stats = lower.distance_stats(&upper).unwrap();
+        assert!((stats.min - 3.0).abs() < 1e-9);
+        assert!((stats.max - 3.0).abs() < 1e-9);
+        assert!((stats.mean - 3.0).abs() < 1e-9);
+
+        let later: tfloat::TFloat = "[0@2018-01-02 08:00:00+00, 10@2018-01-02 09:00:00+00]"
+            .parse()
+            .unwrap();
+        assert!(lower.distance_stats(&later).is_none());
+    }
+
+    #[test]
+    fn constant_segments_tint() {
+        use crate::collections::datetime::tstz_span::TsTzSpan;
+        use tint::TIntTrait;
+
+        meos_initialize("UTC");
+        let tint: tint::TInt =
+            "[1@2018-01-01 08:00:00+00, 1@2018-01-01 08:10:00+00, 2@2018-01-01 08:20:00+00]"
+                .parse()
+                .unwrap();
+        let segments = tint.constant_segments();
+        assert_eq!(
+            segments,
+            vec![
+                (
+                    TsTzSpan::from_str("[2018-01-01 08:00:00+00, 2018-01-01 08:20:00+00)").unwrap(),
+                    1
+                ),
+                (
+                    TsTzSpan::from_str("[2018-01-01 08:20:00+00, 2018-01-01 08:20:00+00]").unwrap(),
+                    2
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn defined_sequences_tint() {
+        meos_initialize("UTC");
+        let tint: tint::TIntSequenceSet = tint::TInt::from_str(
+            "{[1@2018-01-01 08:00:00+00, 2@2018-01-01 08:10:00+00], [3@2018-01-01 09:00:00+00, 4@2018-01-01 09:10:00+00]}",
+        )
+        .unwrap()
+        .try_into()
+        .unwrap();
+        let sequences = tint.defined_sequences();
+        assert_eq!(sequences.len(), 2);
+        assert_eq!(sequences[0].start_value(), 1);
+        assert_eq!(sequences[1].start_value(), 3);
+        assert_eq!(sequences[1].end_timestamp(), tint.end_timestamp());
+    }
+
+    #[test]
+    fn value_span_in_window_tfloat() {
+        use crate::collections::{base::span::Span, datetime::tstz_span::TsTzSpan};
+
+        meos_initialize("UTC");
+        let ramp: tfloat::TFloat = "[0@2018-01-01 08:00:00+00, 60@2018-01-01 09:00:00+00]"
+            .parse()
+            .unwrap();
+        let window =
+            TsTzSpan::from_str("[2018-01-01 08:15:00+00, 2018-01-01 08:30:00+00]").unwrap();
+        let sub_range = ramp.value_span_in_window(&window).unwrap();
+        let full_range = ramp.value_span_in_window(&ramp.timespan()).unwrap();
+        assert!((sub_range.lower() - 15.0).abs() < 1e-9);
+        assert!((sub_range.upper() - 30.0).abs() < 1e-9);
+        assert!(sub_range.upper() - sub_range.lower() < full_range.upper() - full_range.lower());
+
+        let outside =
+            TsTzSpan::from_str("[2018-01-02 08:00:00+00, 2018-01-02 09:00:00+00]").unwrap();
+        assert!(ramp.value_span_in_window(&outside).is_none());
+    }
+
+    #[test]
+    fn time_weighted_sum_tfloat() {
+        meos_initialize("UTC");
+        let stepwise: tfloat::TFloat = "Interp=Step;[2@2018-01-01 08:00:00+00, 5@2018-01-01 08:10:00+00, 1@2018-01-01 08:40:00+00]"
+            .parse()
+            .unwrap();
+        let minute = 60_000_000.0;
+        let expected = 2.0 * 10.0 * minute + 5.0 * 30.0 * minute;
+        assert!((stepwise.time_weighted_sum() - expected).abs() < 1e-3);
+        assert!((stepwise.time_weighted_sum() - stepwise.integral()).abs() < 1e-3);
+
+        let discrete: tfloat::TFloat =
+            "{2@2018-01-01 08:00:00+00, 5@2018-01-01 08:10:00+00, 1@2018-01-01 08:40:00+00}"
+                .parse()
+                .unwrap();
+        assert!((discrete.time_weighted_sum() - expected).abs() < 1e-3);
+    }
+
+    #[test]
+    fn try_append_sequence_tfloat() {
+        use crate::temporal::tsequence_set::TSequenceSet;
+
+        meos_initialize("UTC");
+        let set: tfloat::TFloatSequenceSet = tfloat::TFloat::from_str(
+            "{[1@2018-01-01 08:00:00+00, 2@2018-01-01 08:10:00+00], [3@2018-01-01 09:00:00+00, 4@2018-01-01 09:10:00+00]}",
+        )
+        .unwrap()
+        .try_into()
+        .unwrap();
+        let later: tfloat::TFloatSequence =
+            tfloat::TFloat::from_str("[5@2018-01-01 10:00:00+00, 6@2018-01-01 10:10:00+00]")
+                .unwrap()
+                .try_into()
+                .unwrap();
+        let earlier: tfloat::TFloatSequence =
+            tfloat::TFloat::from_str("[5@2018-01-01 07:00:00+00, 6@2018-01-01 07:10:00+00]")
+                .unwrap()
+                .try_into()
+                .unwrap();
+
+        let appended = set.try_append_sequence(&later).unwrap();
+        assert_eq!(appended.end_timestamp(), later.end_timestamp());
+        assert!(set.try_append_sequence(&earlier).is_err());
+    }
+
+    #[test]
+    fn to_string_in_tz_tint() {
+        meos_initialize("UTC");
+        let tint: tint::TIntSequence =
+            tint::TInt::from_str("[1@2019-07-08 10:00:00+00, 2@2019-07-08 11:00:00+00]")
+                .unwrap()
+                .try_into()
+                .unwrap();
+        assert_eq!(
+            tint.to_string_in_tz(&Utc),
+            "[1@2019-07-08 10:00:00+00, 2@2019-07-08 11:00:00+00]"
+        );
+        assert_eq!(
+            tint.to_string_in_tz(&chrono_tz::Europe::Paris),
+            "[1@2019-07-08 12:00:00+02, 2@2019-07-08 13:00:00+02]"
+        );
+        assert_eq!(
+            format!("{tint:?}"),
+            "[1@2019-07-08 10:00:00+00, 2@2019-07-08 11:00:00+00]"
+        );
+    }
+
+    #[test]
+    fn snap_time_tint() {
+        meos_initialize("UTC");
+        let tint: tint::TIntSequence = tint::TInt::from_str(
+            "[1@2019-07-08 10:00:20+00, 2@2019-07-08 10:00:40+00, 3@2019-07-08 10:01:10+00, 4@2019-07-08 10:02:50+00]",
+        )
+        .unwrap()
+        .try_into()
+        .unwrap();
+        let origin = Utc.with_ymd_and_hms(2019, 7, 8, 0, 0, 0).unwrap();
+        let snapped = tint.snap_time(TimeDelta::minutes(1), origin);
+        let expected: tint::TIntSequence = tint::TInt::from_str(
+            "[1@2019-07-08 10:00:00+00, 2@2019-07-08 10:01:00+00, 4@2019-07-08 10:03:00+00]",
+        )
+        .unwrap()
+        .try_into()
+        .unwrap();
+        assert_eq!(snapped, expected);
+    }
+
+    #[test]
+    fn overlap_duration_tfloat() {
+        meos_initialize("UTC");
+        let first = tfloat::TFloat::from_str("[1.5@2019-07-08, 2.5@2019-07-10]").unwrap();
+        let second = tfloat::TFloat::from_str("[3.5@2019-07-09, 4.5@2019-07-12]").unwrap();
+        assert_eq!(first.overlap_duration(&second), TimeDelta::days(1));
+        assert_eq!(second.overlap_duration(&first), TimeDelta::days(1));
+
+        let disjoint = tfloat::TFloat::from_str("[1.5@2019-08-01, 2.5@2019-08-02]").unwrap();
+        assert_eq!(first.overlap_duration(&disjoint), TimeDelta::zero());
+    }
+
+    #[test]
+    fn align_start_tfloat() {
+        meos_initialize("UTC");
+        let first = tfloat::TFloat::from_str("[1.5@2019-07-08, 2.5@2019-07-10]").unwrap();
+        let second = tfloat::TFloat::from_str("[3.5@2020-01-01 12:00:00, 4.5@2020-01-02]").unwrap();
+        let t0 = Utc.with_ymd_and_hms(2000, 1, 1, 0, 0, 0).unwrap();
+
+        let (first, second) = (first.align_start(t0), second.align_start(t0));
+        assert_eq!(first.start_instant().timestamp(), t0);
+        assert_eq!(
+            first.start_instant().timestamp(),
+            second.start_instant().timestamp()
+        );
+        assert_eq!(first.start_value(), 1.5);
+        assert_eq!(second.end_timestamp(), t0 + TimeDelta::hours(12));
+    }
+
+    #[test]
+    fn fill_gaps_hold_tint() {
+        meos_initialize("UTC");
+        let tint = tint::TInt::from_str(
+            "{[1@2019-07-08 10:00:00+00, 2@2019-07-08 10:05:00+00], [3@2019-07-08 10:06:00+00, 4@2019-07-08 10:10:00+00], [5@2019-07-08 11:00:00+00]}",
+        )
+        .unwrap();
+        let filled = tint.fill_gaps(TimeDelta::minutes(1), FillMethod::Hold);
+        assert_eq!(filled.defined_sequences().len(), 2);
+        assert_eq!(
+            filled.value_at_timestamp(Utc.with_ymd_and_hms(2019, 7, 8, 10, 5, 30).unwrap()),
+            Some(2)
+        );
+
+        let filled = tint.fill_gaps(TimeDelta::hours(1), FillMethod::Hold);
+        assert_eq!(filled.defined_sequences().len(), 1);
+        assert_eq!(filled.duration(false), filled.duration(true));
+    }
+
+    #[test]
+    fn wkb_roundtrip_tint() {
+        use crate::{MeosEnum, WKBVariant};
+
+        meos_initialize("UTC");
+        let tint: tint::TInt = "[1@2018-01-01 08:00:00+00, 2@2018-01-01 08:10:00+00]"
+            .parse()
+            .unwrap();
+        let wkb = tint.as_wkb(WKBVariant::NDR);
+        let parsed = tint::TInt::from_wkb(&wkb);
+        assert_eq!(parsed, tint);
+        assert_eq!(parsed.as_wkb(WKBVariant::NDR), wkb);
+
+        let hexwkb = tint.as_hexwkb(WKBVariant::NDR);
+        let parsed = tint::TInt::from_hexwkb(hexwkb.as_bytes());
+        assert_eq!(parsed, tint);
+        assert_eq!(parsed.as_hexwkb(WKBVariant::NDR), hexwkb);
+    }
+
+    #[test]
+    fn intervals_stepwise_tint() {
+        meos_initialize("UTC");
+        let tint = tint::TInt::from_str(
+            "{[1@2019-07-08 10:00:00+00, 3@2019-07-08 10:10:00+00, 2@2019-07-08 10:20:00+00], [2@2019-07-08 11:00:00+00, 2@2019-07-08 11:30:00+00]}",
+        )
+        .unwrap();
+        let spans = [
+            "[2019-07-08 10:00:00+00, 2019-07-08 10:10:00+00)",
+            "[2019-07-08 10:10:00+00, 2019-07-08 10:20:00+00)",
+            "[2019-07-08 10:20:00+00, 2019-07-08 10:20:00+00]",
+            "[2019-07-08 11:00:00+00, 2019-07-08 11:30:00+00]",
+        ]
+        .map(|span| TsTzSpan::from_str(span).unwrap());
+        let expected: Vec<_> = spans.into_iter().zip([1, 3, 2, 2]).collect();
+        assert_eq!(tint.intervals(), expected);
+    }
+
+    #[test]
+    fn num_sequences_and_total_instants_tint() {
+        meos_initialize("UTC");
+        let tint: tint::TIntSequenceSet = tint::TInt::from_str(
+            "{[1@2019-07-08 10:00:00+00, 2@2019-07-08 10:10:00+00, 3@2019-07-08 10:20:00+00], [4@2019-07-08 11:00:00+00, 5@2019-07-08 11:10:00+00]}",
+        )
+        .unwrap()
+        .try_into()
+        .unwrap();
+        assert_eq!(tint.num_sequences(), 2);
+        assert_eq!(tint.num_total_instants(), 5);
+    }
+
+    #[test]
+    fn at_and_minus_span_tfloat() {
+        meos_initialize("UTC");
+        let ramp: tfloat::TFloat = "[1@2019-07-01 00:00:00+00, 5@2019-07-05 00:00:00+00]"
+            .parse()
+            .unwrap();
+        let span: FloatSpan = (2.0..4.0).into();
+
+        let kept = ramp.at_span(&span).unwrap();
+        assert_eq!(
+            kept.time(),
+            TsTzSpanSet::from_str("{[2019-07-02 00:00:00+00, 2019-07-04 00:00:00+00)}").unwrap()
+        );
+        let removed = ramp.minus_span(&span).unwrap();
+        assert_eq!(
+            removed.time(),
+            TsTzSpanSet::from_str(
+                "{[2019-07-01 00:00:00+00, 2019-07-02 00:00:00+00), [2019-07-04 00:00:00+00, 2019-07-05 00:00:00+00]}"
+            )
+            .unwrap()
+        );
+
+        let span_set = FloatSpanSet::from_str("{[2, 4)}").unwrap();
+        assert_eq!(ramp.at_span_set(&span_set).unwrap(), kept);
+        assert_eq!(ramp.minus_span_set(&span_set).unwrap(), removed);
+
+        assert!(ramp.at_span(&FloatSpan::from(6.0..7.0)).is_none());
+        assert!(ramp.minus_span(&FloatSpan::from(0.0..10.0)).is_none());
+    }
+
+    #[test]
+    fn at_and_minus_time_tint() {
+        meos_initialize("UTC");
+        let tint = tint::TInt::from_str(
+            "[1@2019-07-01 00:00:00+00, 2@2019-07-03 00:00:00+00, 3@2019-07-05 00:00:00+00]",
+        )
+        .unwrap();
+        let span = TsTzSpan::from_str("[2019-07-02 00:00:00+00, 2019-07-04 00:00:00+00]").unwrap();
+        let kept = tint.at_time(&span).unwrap();
+        assert_eq!(kept.timespan(), span);
+        let removed = tint.minus_time(&span).unwrap();
+        assert_eq!(
+            removed.time(),
+            TsTzSpanSet::from_str(
+                "{[2019-07-01 00:00:00+00, 2019-07-02 00:00:00+00), (2019-07-04 00:00:00+00, 2019-07-05 00:00:00+00]}"
+            )
+            .unwrap()
+        );
+        let span_set =
+            TsTzSpanSet::from_str("{[2019-07-02 00:00:00+00, 2019-07-04 00:00:00+00]}").unwrap();
+        assert_eq!(tint.at_time_set(&span_set).unwrap(), kept);
+        assert_eq!(tint.minus_time_set(&span_set).unwrap(), removed);
+        assert_eq!(
+            tint.at_timestamp(Utc.with_ymd_and_hms(2019, 7, 3, 0, 0, 0).unwrap())
+                .unwrap()
+                .start_value(),
+            2
+        );
+
+        let outside =
+            TsTzSpan::from_str("[2019-08-01 00:00:00+00, 2019-08-02 00:00:00+00]").unwrap();
+        assert!(tint.at_time(&outside).is_none());
+        assert!(tint
+            .at_time_set(
+                &TsTzSpanSet::from_str("{[2019-08-01 00:00:00+00, 2019-08-02 00:00:00+00]}")
+                    .unwrap()
+            )
+            .is_none());
+        assert!(tint
+            .at_timestamp(Utc.with_ymd_and_hms(2019, 8, 1, 0, 0, 0).unwrap())
+            .is_none());
+        assert!(tint.minus_time(&tint.timespan()).is_none());
+    }
+
+    #[test]
+    fn temporal_sum_tint() {
+        meos_initialize("UTC");
+        let items: Vec<tint::TInt> = [
+            "[1@2018-01-01 08:00:00+00, 1@2018-01-01 08:30:00+00]",
+            "[2@2018-01-01 08:10:00+00, 2@2018-01-01 08:40:00+00]",
+            "[3@2018-01-01 08:20:00+00, 3@2018-01-01 08:50:00+00]",
+        ]
+        .iter()
+        .map(|item| item.parse().unwrap())
+        .collect();
+        let at = |minute| Utc.with_ymd_and_hms(2018, 1, 1, 8, minute, 0).unwrap();
+
+        let sum = tint::TInt::temporal_sum(&items).unwrap();
+        assert_eq!(sum.value_at_timestamp(at(5)), Some(1));
+        assert_eq!(sum.value_at_timestamp(at(15)), Some(3));
+        assert_eq!(sum.value_at_timestamp(at(25)), Some(6));
+        assert_eq!(sum.value_at_timestamp(at(45)), Some(3));
+        assert_eq!(
+            sum.timespan(),
+            TsTzSpan::from_str("[2018-01-01 08:00:00+00, 2018-01-01 08:50:00+00]").unwrap()
+        );
+
+        let max = tint::TInt::temporal_max(&items).unwrap();
+        assert_eq!(max.value_at_timestamp(at(25)), Some(3));
+        let avg = tint::TInt::temporal_avg(&items).unwrap();
+        assert_eq!(avg.value_at_timestamp(at(25)), Some(2.0));
+
+        assert!(tint::TInt::temporal_sum(&[]).is_none());
+    }
+
+    #[test]
+    fn interpolation_predicates() {
+        meos_initialize("UTC");
+        let linear = tfloat::TFloat::from_str("[1@2019-07-08, 2@2019-07-10]").unwrap();
+        let stepwise = tint::TInt::from_str("[1@2019-07-08, 2@2019-07-10]").unwrap();
+        let discrete = tint::TInt::from_str("{1@2019-07-08, 2@2019-07-10}").unwrap();
+        assert_eq!(
+            (
+                linear.is_linear(),
+                linear.is_stepwise(),
+                linear.is_discrete()
+            ),
+            (true, false, false)
+        );
+        assert_eq!(
+            (
+                stepwise.is_linear(),
+                stepwise.is_stepwise(),
+                stepwise.is_discrete()
+            ),
+            (false, true, false)
+        );
+        assert_eq!(
+            (
+                discrete.is_linear(),
+                discrete.is_stepwise(),
+                discrete.is_discrete()
+            ),
+            (false, false, true)
+        );
+    }
+
+    #[test]
+    fn temporal_greater_than_value_tfloat() {
+        meos_initialize("UTC");
+        let tfloat = tfloat::TFloat::from_str("[5@2019-07-08, 15@2019-07-10]").unwrap();
+        let result = tfloat.temporal_greater_than_value(&10.0);
+        assert_eq!(
+            result,
+            TBool::from_str("{[f@2019-07-08, f@2019-07-09], (t@2019-07-09, t@2019-07-10]}")
+                .unwrap()
+        );
+        let at = |day, hour| Utc.with_ymd_and_hms(2019, 7, day, hour, 0, 0).unwrap();
+        assert_eq!(result.value_at_timestamp(at(8, 12)), Some(false));
+        assert_eq!(result.value_at_timestamp(at(9, 0)), Some(false));
+        assert_eq!(result.value_at_timestamp(at(9, 12)), Some(true));
+        assert_eq!(
+            tfloat
+                .temporal_lower_than_value(&10.0)
+                .value_at_timestamp(at(8, 12)),
+            Some(true)
+        );
+    }
+
+    #[test]
+    fn at_time_of_day_tint() {
+        meos_initialize("UTC");
+        let tint = tint::TInt::from_str(
+            "{1@2019-07-08 03:00:00+00, 2@2019-07-08 10:00:00+00, 3@2019-07-08 20:00:00+00, \
+            4@2019-07-09 12:00:00+00, 5@2019-07-09 23:00:00+00}",
+        )
+        .unwrap();
+        let time = |hour| NaiveTime::from_hms_opt(hour, 0, 0).unwrap();
+
+        let business_hours = tint.at_time_of_day(time(9), time(17)).unwrap();
+        assert_eq!(business_hours.values(), vec![2, 4]);
+        assert_eq!(business_hours.num_instants(), 2);
+
+        let night = tint.at_time_of_day(time(22), time(6)).unwrap();
+        assert_eq!(night.values(), vec![1, 5]);
+
+        assert!(tint.at_time_of_day(time(6), time(8)).is_none());
+    }
+
+    #[test]
+    fn append_instant_tfloat() {
+        meos_initialize("UTC");
+        let at = |minute| Utc.with_ymd_and_hms(2019, 7, 8, 10, minute, 0).unwrap();
+        let sequence: tfloat::TFloatSequence =
+            tfloat::TFloat::from_str("[1@2019-07-08 10:00:00+00, 2@2019-07-08 10:01:00+00]")
+                .unwrap()
+                .try_into()
+                .unwrap();
+
+        let appended = sequence
+            .clone()
+            .append_instant(tfloat::TFloatInstant::from((3.0, at(2))), None, None)
+            .unwrap();
+        assert_eq!(appended.num_instants(), 3);
+        assert_eq!(appended.end_value(), 3.0);
+
+        let with_gap = sequence
+            .clone()
+            .append_instant(
+                tfloat::TFloatInstant::from((3.0, at(30))),
+                None,
+                Some(TimeDelta::minutes(5)),
+            )
+            .unwrap();
+        assert!(matches!(with_gap, tfloat::TFloat::SequenceSet(_)));
+        assert_eq!(with_gap.num_instants(), 3);
+        assert_eq!(with_gap.value_at_timestamp(at(10)), None);
+
+        let far = sequence
+            .clone()
+            .append_instant(tfloat::TFloatInstant::from((30.0, at(2))), Some(10.0), None)
+            .unwrap();
+        assert!(matches!(far, tfloat::TFloat::SequenceSet(_)));
+
+        assert!(sequence
+            .append_instant(tfloat::TFloatInstant::from((3.0, at(0))), None, None)
+            .is_err());
+    }
+
+    #[test]
+    fn insert_update_delete_tfloat() {
+        meos_initialize("UTC");
+        let at = |minute| Utc.with_ymd_and_hms(2019, 7, 8, 10, minute, 0).unwrap();
+        let sequence =
+            tfloat::TFloat::from_str("[1@2019-07-08 10:00:00+00, 3@2019-07-08 10:02:00+00]")
+                .unwrap();
+
+        let inserted = sequence
+            .insert(
+                tfloat::TFloat::from_str("5@2019-07-08 10:01:00+00").unwrap(),
+                true,
+            )
+            .unwrap();
+        assert_eq!(inserted.num_instants(), 3);
+        assert_eq!(inserted.value_at_timestamp(at(1)), Some(5.0));
+        assert!(sequence
+            .insert(
+                tfloat::TFloat::from_str("5@2019-07-08 10:00:00+00").unwrap(),
+                true
+            )
+            .is_err());
+
+        let updated = inserted
+            .update(
+                tfloat::TFloat::from_str("7@2019-07-08 10:01:00+00").unwrap(),
+                true,
+            )
+            .unwrap();
+        assert_eq!(updated.value_at_timestamp(at(1)), Some(7.0));
+
+        let deleted = updated.delete_at_timestamp(at(1), true).unwrap();
+        assert_eq!(deleted, sequence);
+        assert!(sequence
+            .delete_at_tstz_span(TsTzSpan::from(at(0)..=at(2)), true)
+            .is_none());
+    }
+
+    #[test]
+    fn arithmetic_operators() {
+        meos_initialize("UTC");
+        let tint = tint::TInt::from_str("[1@2019-07-08, 3@2019-07-10]").unwrap();
+        assert_eq!(
+            &tint + 5,
+            tint::TInt::from_str("[6@2019-07-08, 8@2019-07-10]").unwrap()
+        );
+        assert_eq!(
+            10 - tint,
+            tint::TInt::from_str("[9@2019-07-08, 7@2019-07-10]").unwrap()
+        );
+
+        let tfloat = tfloat::TFloat::from_str("[1.5@2019-07-08, 2.5@2019-07-10]").unwrap();
+        assert_eq!(
+            &tfloat * 2.0,
+            tfloat::TFloat::from_str("[3@2019-07-08, 5@2019-07-10]").unwrap()
+        );
+
+        let other = tfloat::TFloat::from_str("[0.5@2019-07-08, 1.5@2019-07-10]").unwrap();
+        assert_eq!(
+            &tfloat + &other,
+            Some(tfloat::TFloat::from_str("[2@2019-07-08, 4@2019-07-10]").unwrap())
+        );
+        let disjoint = tfloat::TFloat::from_str("[1@2019-08-01, 2@2019-08-02]").unwrap();
+        assert_eq!(&tfloat - &disjoint, None);
+
+        let crossing = tfloat::TFloat::from_str("[-1@2019-07-08, 1@2019-07-10]").unwrap();
+        assert!((&tfloat / &crossing).is_err());
+        assert!((1.0 / &crossing).is_err());
+        assert!((&tfloat / &other).unwrap().is_some());
+    }
+
+    #[test]
+    fn divide_tint_by_zero() {
+        meos_initialize("UTC");
+        let tint = tint::TInt::from_str("[4@2019-07-08, 8@2019-07-10]").unwrap();
+        assert_eq!(
+            (&tint / 2).unwrap(),
+            tint::TInt::from_str("[2@2019-07-08, 4@2019-07-10]").unwrap()
+        );
+        assert!((&tint / 0).is_err());
+        assert!((tint / 0).is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn serde_roundtrip_tnumber() {
+        meos_initialize("UTC");
+        let tint = tint::TInt::from_str("{1@2020-01-01, 3@2020-01-02}").unwrap();
+        let json = serde_json::to_string(&tint).unwrap();
+        assert_eq!(serde_json::from_str::<tint::TInt>(&json).unwrap(), tint);
+
+        let tfloat = tfloat::TFloat::from_str("[1.123456789@2020-01-01, 3.5@2020-01-02]").unwrap();
+        let json = serde_json::to_string(&tfloat).unwrap();
+        assert_eq!(
+            serde_json::from_str::<tfloat::TFloat>(&json).unwrap(),
+            tfloat
+        );
+        assert!(serde_json::from_str::<tint::TInt>("\"1@\"").is_err());
+    }
+
+    #[test]
+    fn mode_tint() {
+        meos_initialize("UTC");
+        let tint = tint::TInt::from_str(
+            "[1@2020-01-01, 2@2020-01-02, 3@2020-01-05, 1@2020-01-06, 1@2020-01-07]",
+        )
+        .unwrap();
+        assert_eq!(tint.mode(), Some(2));
+
+        let discrete = tint::TInt::from_str("{1@2020-01-01, 2@2020-01-02, 2@2020-01-03}").unwrap();
+        assert_eq!(discrete.mode(), Some(2));
+        assert_eq!(
+            tint::TInt::from_str("4@2020-01-01").unwrap().mode(),
+            Some(4)
+        );
+    }
+
+    #[test]
+    fn from_mfjson_tint() {
+        use crate::{temporal::JSONCVariant, MeosEnum};
+
+        meos_initialize("UTC");
+        let tint = tint::TInt::from_str("[1@2020-01-01, 3@2020-01-02]").unwrap();
+        let mfjson = tint.as_mfjson(false, JSONCVariant::Plain, 15, "");
+        assert_eq!(tint::TInt::from_mfjson(&mfjson).unwrap(), tint);
+
+        let truncated = &mfjson[..mfjson.len() / 2];
+        assert!(tint::TInt::from_mfjson(truncated).is_err());
+        assert!(tint::TInt::from_mfjson("").is_err());
+    }
+
+    #[test]
+    fn new_with_bounds_tfloat() {
+        meos_initialize("UTC");
+        let at = |minute| Utc.with_ymd_and_hms(2019, 7, 8, 10, minute, 0).unwrap();
+        let instants: Vec<tfloat::TFloatInstant> = [(1.0, at(0)), (2.0, at(1)), (4.0, at(2))]
+            .into_iter()
+            .map(tfloat::TFloatInstant::from)
+            .collect();
+        let build = |interpolation, lower_inclusive, upper_inclusive| {
+            tfloat::TFloatSequence::new_with_bounds(
+                &instants,
+                interpolation,
+                lower_inclusive,
+                upper_inclusive,
+            )
+        };
+
+        let discrete = build(TInterpolation::Discrete, true, true).unwrap();
+        let stepwise = build(TInterpolation::Stepwise, true, true).unwrap();
+        let linear = build(TInterpolation::Linear, true, false).unwrap();
+        let outputs = [
+            format!("{discrete:?}"),
+            format!("{stepwise:?}"),
+            format!("{linear:?}"),
+        ];
+        assert!(outputs[0].starts_with('{'));
+        assert!(outputs[1].starts_with("Interp=Step;["));
+        assert!(outputs[2].starts_with('[') && outputs[2].ends_with(')'));
+        assert_ne!(outputs[0], outputs[1]);
+        assert_ne!(outputs[1], outputs[2]);
+        assert_ne!(outputs[0], outputs[2]);
+
+        let open = build(TInterpolation::Linear, false, true).unwrap();
+        assert!(!open.is_lower_inclusive());
+        assert!(open.is_upper_inclusive());
+        assert_eq!(open.value_at_timestamp(at(0)), None);
+
+        assert!(build(TInterpolation::Discrete, false, true).is_err());
+    }
+
+    #[test]
+    fn linear_pieces_tfloat() {
+        use crate::collections::base::span::Span;
+
+        meos_initialize("UTC");
+        let at = |minute| Utc.with_ymd_and_hms(2020, 1, 1, 0, minute, 0).unwrap();
+        let ramp = tfloat::TFloat::from_str(
+            "[0@2020-01-01 00:00:00+00, 60@2020-01-01 00:01:00+00, 0@2020-01-01 00:03:00+00]",
+        )
+        .unwrap();
+        let pieces = ramp.linear_pieces();
+        assert_eq!(pieces.len(), 2);
+        assert_eq!(pieces[0].slope, 1.0);
+        assert_eq!(pieces[0].intercept_value, 0.0);
+        assert_eq!(pieces[1].slope, -0.5);
+        assert_eq!(pieces[1].intercept_value, 60.0);
+
+        let midpoint = at(2);
+        let piece = &pieces[1];
+        let elapsed = (midpoint - piece.span.lower()).num_seconds() as f64;
+        let value = piece.intercept_value + piece.slope * elapsed;
+        assert_eq!(value, 30.0);
+        assert_eq!(ramp.value_at_timestamp(midpoint), Some(value));
+    }
+
+    #[test]
+    fn simplify_tfloat() {
+        use crate::temporal::temporal::SimplifiableTemporal;
+
+        meos_initialize("UTC");
+        let start = Utc.with_ymd_and_hms(2020, 1, 1, 0, 0, 0).unwrap();
+        let instants: Vec<tfloat::TFloatInstant> = (0..100)
+            .map(|i| {
+                let noise = if i % 2 == 0 { 0.001 } else { -0.001 };
+                let value = if i == 0 || i == 99 {
+                    i as f64
+                } else {
+                    i as f64 + noise
+                };
+                tfloat::TFloatInstant::from((value, start + TimeDelta::seconds(i)))
+            })
+            .collect();
+        let dense: tfloat::TFloat =
+            tfloat::TFloatSequence::new(&instants, TInterpolation::Linear).into();
+
+        for simplified in [
+            dense.simplify_douglas_peucker(0.01, false),
+            dense.simplify_douglas_peucker(0.01, true),
+            dense.simplify_max_distance(0.01, true),
+        ] {
+            assert!(simplified.num_instants() < dense.num_instants());
+            assert_eq!(simplified.start_value(), dense.start_value());
+            assert_eq!(simplified.end_value(), dense.end_value());
+            let middle = simplified
+                .value_at_timestamp(start + TimeDelta::seconds(50))
+                .unwrap();
+            assert!((middle - 50.0).abs() < 0.01);
+        }
+
+        let sparse = dense.simplify_min_tdelta(TimeDelta::seconds(10));
+        assert!(sparse.num_instants() <= 11);
+    }
 }
@@ -6,7 +6,7 @@ use std::{
     str::FromStr,
 };
 
-use chrono::{DateTime, TimeZone};
+use chrono::{DateTime, TimeZone, Utc};
 
 use super::tnumber::{impl_meos_enum, impl_temporal_for_tnumber, TNumber};
 use crate::{
@@ -18,15 +18,16 @@ use crate::{
             span_set::SpanSet,
         },
         datetime::{tstz_span::TsTzSpan, tstz_span_set::TsTzSpanSet},
-        number::float_span_set::FloatSpanSet,
+        number::{float_span::FloatSpan, float_span_set::FloatSpanSet},
     },
-    errors::ParseError,
-    factory, impl_from_str,
+    errors::{ParseError, TemporalParseError},
+    factory, impl_from_str, impl_serde, parse_mfjson,
     temporal::{
         interpolation::TInterpolation,
         tbool::{TBool, TBoolInstant, TBoolSequence, TBoolSequenceSet},
         temporal::{
-            impl_always_and_ever_value_equality_functions, impl_ordered_temporal_functions,
+            aggregate, impl_always_and_ever_value_equality_functions,
+            impl_ordered_temporal_functions, impl_ordering_for_temporal,
             impl_simple_traits_for_temporal, OrderedTemporal, SimplifiableTemporal, Temporal,
         },
         tinstant::TInstant,
@@ -60,6 +61,88 @@ pub enum TFloat {
 }
 
 impl_from_str!(TFloat);
+impl_serde!(TFloat, |temporal| unsafe {
+    meos_sys::tfloat_out(temporal.inner(), 15)
+});
+
+impl TFloatTrait for TFloat {}
+impl SimplifiableTemporal for TFloat {}
+
+impl TFloat {
+    /// Computes the temporal sum of all the given temporal floats.
+    ///
+    /// At each instant the result is the sum of the values of the items defined at that instant.
+    ///
+    /// ## Arguments
+    /// * `items` - A slice of temporal floats.
+    ///
+    /// ## Returns
+    /// The aggregated `TFloat`, or `None` if `items` is empty.
+    ///
+    /// MEOS Functions:
+    ///     `tfloat_tsum_transfn`, `temporal_tagg_finalfn`
+    pub fn temporal_sum(items: &[TFloat]) -> Option<TFloat> {
+        aggregate(
+            items,
+            meos_sys::tfloat_tsum_transfn,
+            meos_sys::temporal_tagg_finalfn,
+        )
+    }
+
+    /// Computes the temporal maximum of all the given temporal floats.
+    ///
+    /// ## Arguments
+    /// * `items` - A slice of temporal floats.
+    ///
+    /// ## Returns
+    /// The aggregated `TFloat`, or `None` if `items` is empty.
+    ///
+    /// MEOS Functions:
+    ///     `tfloat_tmax_transfn`, `temporal_tagg_finalfn`
+    pub fn temporal_max(items: &[TFloat]) -> Option<TFloat> {
+        aggregate(
+            items,
+            meos_sys::tfloat_tmax_transfn,
+            meos_sys::temporal_tagg_finalfn,
+        )
+    }
+
+    /// Computes the temporal minimum of all the given temporal floats.
+    ///
+    /// ## Arguments
+    /// * `items` - A slice of temporal floats.
+    ///
+    /// ## Returns
+    /// The aggregated `TFloat`, or `None` if `items` is empty.
+    ///
+    /// MEOS Functions:
+    ///     `tfloat_tmin_transfn`, `temporal_tagg_finalfn`
+    pub fn temporal_min(items: &[TFloat]) -> Option<TFloat> {
+        aggregate(
+            items,
+            meos_sys::tfloat_tmin_transfn,
+            meos_sys::temporal_tagg_finalfn,
+        )
+    }
+
+    /// Computes the temporal average of all the given temporal floats.
+    ///
+    /// ## Arguments
+    /// * `items` - A slice of temporal floats.
+    ///
+    /// ## Returns
+    /// The aggregated `TFloat`, or `None` if `items` is empty.
+    ///
+    /// MEOS Functions:
+    ///     `tnumber_tavg_transfn`, `tnumber_tavg_finalfn`
+    pub fn temporal_avg(items: &[TFloat]) -> Option<TFloat> {
+        aggregate(
+            items,
+            meos_sys::tnumber_tavg_transfn,
+            meos_sys::tnumber_tavg_finalfn,
+        )
+    }
+}
 
 impl MeosEnum for TFloat {
     fn from_instant(inner: *mut meos_sys::TInstant) -> Self {
@@ -80,9 +163,46 @@ impl MeosEnum for TFloat {
         })
     }
 
-    fn from_mfjson(mfjson: &str) -> Self {
-        let cstr = CString::new(mfjson).unwrap();
-        factory::<Self>(unsafe { meos_sys::tfloat_from_mfjson(cstr.as_ptr()) })
+    fn from_mfjson(mfjson: &str) -> Result<Self, TemporalParseError> {
+        parse_mfjson(mfjson, meos_sys::tfloat_from_mfjson)
+    }
+}
+
+/// Summary of the distance between two temporal floats over their common time.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DistanceStats {
+    /// Smallest distance.
+    pub min: f64,
+    /// Largest distance.
+    pub max: f64,
+    /// Time-weighted average distance.
+    pub mean: f64,
+}
+
+/// A piece of a temporal float where the value varies linearly with time, as
+/// `value = intercept_value + slope * (t - span.lower())`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LinearPiece {
+    /// Time during which the piece is defined.
+    pub span: TsTzSpan,
+    /// Change of the value per second.
+    pub slope: f64,
+    /// Value at the start of `span`.
+    pub intercept_value: f64,
+}
+
+/// Returns the rate of change of `segment` in value units per second, which is `0.0` for a
+/// stepwise or instantaneous segment since its start and end values are equal.
+fn segment_rate(segment: &TFloatSequence) -> f64 {
+    let seconds = segment
+        .duration(false)
+        .num_microseconds()
+        .unwrap_or(i64::MAX) as f64
+        / 1e6;
+    if seconds > 0.0 {
+        (segment.end_value() - segment.start_value()) / seconds
+    } else {
+        0.0
     }
 }
 
@@ -106,6 +226,159 @@ pub trait TFloatTrait:
         let modified = unsafe { meos_sys::tfloat_shift_scale_value(self.inner(), d, w) };
         Self::from_inner_as_temporal(modified)
     }
+
+    // ------------------------- Accessors -------------------------------------
+
+    /// Returns every segment of `self` as a linear function of time, e.g. to export it to
+    /// other math tools.
+    ///
+    /// Stepwise segments have a slope of `0.0`, and the instants of discrete sequences are
+    /// returned as pieces with an instantaneous span.
+    ///
+    /// # Returns
+    /// A vector of `LinearPiece` in time order.
+    ///
+    /// # Safety
+    /// This function uses unsafe code to call the `meos_sys::temporal_segments` function.
+    fn linear_pieces(&self) -> Vec<LinearPiece> {
+        if unsafe { (*self.inner()).subtype } as u32 == meos_sys::tempSubtype_TINSTANT {
+            return vec![LinearPiece {
+                span: self.timespan(),
+                slope: 0.0,
+                intercept_value: self.start_value(),
+            }];
+        }
+        self.segments()
+            .iter()
+            .map(|segment| LinearPiece {
+                span: segment.timespan(),
+                slope: segment_rate(segment),
+                intercept_value: segment.start_value(),
+            })
+            .collect()
+    }
+
+    /// Returns the integral of `self` restricted to the time window `span`.
+    ///
+    /// Like `integral`, the result is expressed in value·microseconds.
+    ///
+    /// # Arguments
+    /// * `span` - The time window to integrate over.
+    ///
+    /// # Returns
+    /// The integral of `self` within `span`, or `0.0` if `self` is not defined during `span`.
+    ///
+    /// # Safety
+    /// This function uses unsafe code to call the `meos_sys::temporal_at_tstzspan` and
+    /// `meos_sys::tnumber_integral` functions.
+    fn integral_over(&self, span: &TsTzSpan) -> f64 {
+        let window = unsafe { meos_sys::temporal_at_tstzspan(self.inner(), span.inner()) };
+        if window.is_null() {
+            return 0.0;
+        }
+        let result = unsafe { meos_sys::tnumber_integral(window) };
+        unsafe { libc::free(window as *mut c_void) };
+        result
+    }
+
+    /// Returns the sum of every value of `self` multiplied by the time it holds, that is, until
+    /// the next instant of the same sequence.
+    ///
+    /// This is meant for discrete series, whose `integral` is always `0.0`, where every
+    /// sample is considered valid until the next one. For stepwise interpolation it equals
+    /// `integral`, but for linear interpolation it differs from it, as the value is not
+    /// interpolated between instants. Like `integral`, the result is expressed in
+    /// value·microseconds.
+    ///
+    /// # Returns
+    /// The time-weighted sum of the values of `self`.
+    ///
+    /// # Safety
+    /// This function uses unsafe code to call the `meos_sys::temporal_sequences`,
+    /// `meos_sys::temporal_instants` and `meos_sys::tfloat_start_value` functions.
+    fn time_weighted_sum(&self) -> f64 {
+        let weighted_sum = |temporal: *const meos_sys::Temporal| unsafe {
+            let mut count = 0;
+            let instants = meos_sys::temporal_instants(temporal, ptr::addr_of_mut!(count));
+            let sum = std::slice::from_raw_parts(instants, count as usize)
+                .windows(2)
+                .map(|pair| {
+                    let value = meos_sys::tfloat_start_value(pair[0] as *const meos_sys::Temporal);
+                    value * ((*pair[1]).t - (*pair[0]).t) as f64
+                })
+                .sum::<f64>();
+            libc::free(instants as *mut c_void);
+            sum
+        };
+        if self.interpolation() == TInterpolation::Discrete {
+            return weighted_sum(self.inner());
+        }
+        self.defined_sequences()
+            .iter()
+            .map(|sequence| weighted_sum(sequence.inner()))
+            .sum()
+    }
+
+    /// Returns the time span and rate of change of every segment of `self`.
+    ///
+    /// # Returns
+    /// A vector of `(span, rate)` pairs, where `rate` is the slope of the segment in value units
+    /// per second. Stepwise segments have a rate of `0.0`, and discrete values have no segments.
+    ///
+    /// # Safety
+    /// This function uses unsafe code to call the `meos_sys::temporal_segments` function.
+    fn segment_rates(&self) -> Vec<(TsTzSpan, f64)> {
+        self.segments()
+            .iter()
+            .filter(|segment| segment.num_instants() > 1)
+            .map(|segment| (segment.timespan(), segment_rate(segment)))
+            .collect()
+    }
+
+    /// Returns the minimum, maximum and time-weighted average of the distance between `self`
+    /// and `other`.
+    ///
+    /// # Arguments
+    /// * `other` - The temporal float to measure the distance to.
+    ///
+    /// # Returns
+    /// The `DistanceStats` over the common time of `self` and `other`, or `None` if they are
+    /// never defined at the same time.
+    ///
+    /// # Safety
+    /// This function uses unsafe code to call the `meos_sys::distance_tnumber_tnumber`,
+    /// `meos_sys::tfloat_min_value`, `meos_sys::tfloat_max_value` and `meos_sys::tnumber_twavg`
+    /// functions.
+    fn distance_stats(&self, other: &Self) -> Option<DistanceStats> {
+        unsafe {
+            let distance = meos_sys::distance_tnumber_tnumber(self.inner(), other.inner());
+            if distance.is_null() {
+                return None;
+            }
+            let stats = DistanceStats {
+                min: meos_sys::tfloat_min_value(distance),
+                max: meos_sys::tfloat_max_value(distance),
+                mean: meos_sys::tnumber_twavg(distance),
+            };
+            libc::free(distance as *mut c_void);
+            Some(stats)
+        }
+    }
+
+    // ------------------------- Restrictions ----------------------------------
+
+    /// Returns a new temporal object restricted to the times where the value of `self` lies within `span`.
+    ///
+    /// This is `TNumber::at_span` for a `FloatSpan`.
+    ///
+    /// # Arguments
+    /// * `span` - The `FloatSpan` of values to keep.
+    ///
+    /// # Returns
+    /// The restricted temporal float, or `None` if `self` never takes a value within `span`.
+    fn at_values_in_span(&self, span: &FloatSpan) -> Option<Self> {
+        self.at_span(span)
+    }
 }
 
 pub struct TFloatInstant {
@@ -162,6 +435,24 @@ impl TFloatSequence {
             meos_sys::tfloatseq_from_base_tstzspan(value, time_span.inner(), interpolation as u32)
         })
     }
+
+    /// Creates a temporal float that holds `value` over the whole `time_span`.
+    ///
+    /// Same as `from_value_and_tstz_span`, without its timezone type parameter.
+    ///
+    /// ## Arguments
+    /// * `value` - The constant value.
+    /// * `time_span` - The time span during which the value holds.
+    /// * `interpolation` - The interpolation of the resulting sequence.
+    ///
+    /// ## Returns
+    /// A new temporal float sequence.
+    ///
+    /// MEOS Functions:
+    ///     `tfloatseq_from_base_tstzspan`
+    pub fn constant(value: f64, time_span: TsTzSpan, interpolation: TInterpolation) -> Self {
+        Self::from_value_and_tstz_span::<Utc>(value, time_span, interpolation)
+    }
 }
 
 impl TSequence for TFloatSequence {
@@ -19,13 +19,14 @@ use crate::{
         datetime::{tstz_span::TsTzSpan, tstz_span_set::TsTzSpanSet},
         number::int_span_set::IntSpanSet,
     },
-    errors::ParseError,
-    factory, impl_from_str,
+    errors::{ParseError, TemporalParseError},
+    factory, impl_from_str, impl_serde, parse_mfjson,
     temporal::{
         interpolation::TInterpolation,
         tbool::{TBool, TBoolInstant, TBoolSequence, TBoolSequenceSet},
         temporal::{
-            impl_always_and_ever_value_equality_functions, impl_ordered_temporal_functions,
+            aggregate, impl_always_and_ever_value_equality_functions,
+            impl_ordered_temporal_functions, impl_ordering_for_temporal,
             impl_simple_traits_for_temporal, OrderedTemporal, Temporal,
         },
         tinstant::TInstant,
@@ -36,7 +37,10 @@ use crate::{
     MeosEnum,
 };
 
-use super::tnumber::{impl_meos_enum, impl_temporal_for_tnumber, TNumber};
+use super::{
+    tfloat::TFloat,
+    tnumber::{impl_meos_enum, impl_temporal_for_tnumber, TNumber},
+};
 
 #[derive(Debug)]
 pub enum TInt {
@@ -46,6 +50,87 @@ pub enum TInt {
 }
 
 impl_from_str!(TInt);
+impl_serde!(TInt, |temporal| unsafe {
+    meos_sys::tint_out(temporal.inner())
+});
+
+impl TIntTrait for TInt {}
+
+impl TInt {
+    /// Computes the temporal sum of all the given temporal integers.
+    ///
+    /// At each instant the result is the sum of the values of the items defined at that instant.
+    ///
+    /// ## Arguments
+    /// * `items` - A slice of temporal integers.
+    ///
+    /// ## Returns
+    /// The aggregated `TInt`, or `None` if `items` is empty.
+    ///
+    /// MEOS Functions:
+    ///     `tint_tsum_transfn`, `temporal_tagg_finalfn`
+    pub fn temporal_sum(items: &[TInt]) -> Option<TInt> {
+        aggregate(
+            items,
+            meos_sys::tint_tsum_transfn,
+            meos_sys::temporal_tagg_finalfn,
+        )
+    }
+
+    /// Computes the temporal maximum of all the given temporal integers.
+    ///
+    /// ## Arguments
+    /// * `items` - A slice of temporal integers.
+    ///
+    /// ## Returns
+    /// The aggregated `TInt`, or `None` if `items` is empty.
+    ///
+    /// MEOS Functions:
+    ///     `tint_tmax_transfn`, `temporal_tagg_finalfn`
+    pub fn temporal_max(items: &[TInt]) -> Option<TInt> {
+        aggregate(
+            items,
+            meos_sys::tint_tmax_transfn,
+            meos_sys::temporal_tagg_finalfn,
+        )
+    }
+
+    /// Computes the temporal minimum of all the given temporal integers.
+    ///
+    /// ## Arguments
+    /// * `items` - A slice of temporal integers.
+    ///
+    /// ## Returns
+    /// The aggregated `TInt`, or `None` if `items` is empty.
+    ///
+    /// MEOS Functions:
+    ///     `tint_tmin_transfn`, `temporal_tagg_finalfn`
+    pub fn temporal_min(items: &[TInt]) -> Option<TInt> {
+        aggregate(
+            items,
+            meos_sys::tint_tmin_transfn,
+            meos_sys::temporal_tagg_finalfn,
+        )
+    }
+
+    /// Computes the temporal average of all the given temporal integers.
+    ///
+    /// ## Arguments
+    /// * `items` - A slice of temporal integers.
+    ///
+    /// ## Returns
+    /// The aggregated `TFloat`, or `None` if `items` is empty.
+    ///
+    /// MEOS Functions:
+    ///     `tnumber_tavg_transfn`, `tnumber_tavg_finalfn`
+    pub fn temporal_avg(items: &[TInt]) -> Option<TFloat> {
+        aggregate(
+            items,
+            meos_sys::tnumber_tavg_transfn,
+            meos_sys::tnumber_tavg_finalfn,
+        )
+    }
+}
 
 impl MeosEnum for TInt {
     fn from_instant(inner: *mut meos_sys::TInstant) -> Self {
@@ -60,9 +145,8 @@ impl MeosEnum for TInt {
         Self::SequenceSet(TIntSequenceSet::from_inner(inner))
     }
 
-    fn from_mfjson(mfjson: &str) -> Self {
-        let cstr = CString::new(mfjson).unwrap();
-        factory::<Self>(unsafe { meos_sys::tint_from_mfjson(cstr.as_ptr()) })
+    fn from_mfjson(mfjson: &str) -> Result<Self, TemporalParseError> {
+        parse_mfjson(mfjson, meos_sys::tint_from_mfjson)
     }
 }
 
@@ -93,6 +177,24 @@ pub trait TIntTrait:
         let modified = unsafe { meos_sys::tint_shift_scale_value(self.inner(), d, w) };
         Self::from_inner_as_temporal(modified)
     }
+
+    // ------------------------- Accessors -------------------------------------
+
+    /// Returns every maximal period during which `self` keeps the same value, with that value.
+    ///
+    /// Consecutive segments with the same value are merged, so the result describes `self` as a
+    /// step function with as few pieces as possible. Instants of discrete sequences are never
+    /// merged, as `self` is not defined between them. Temporal integers are never linear, so
+    /// this is the same as `Temporal::intervals`.
+    ///
+    /// # Returns
+    /// A vector of `(span, value)` pairs in time order.
+    ///
+    /// # Safety
+    /// This function uses unsafe code to call the `meos_sys::temporal_segments` function.
+    fn constant_segments(&self) -> Vec<(TsTzSpan, i32)> {
+        self.intervals()
+    }
 }
 
 macro_rules! impl_debug {
@@ -256,12 +358,12 @@ impl FromIterator<TInt> for TInt {
             (TInt::Sequence(acc_value), TInt::Sequence(item_value)) => {
                 acc_value.append_sequence(item_value)
             }
-            (TInt::Sequence(acc_value), TInt::Instant(item_value)) => {
-                acc_value.append_instant(item_value, None, None)
-            }
-            (TInt::SequenceSet(acc_value), TInt::Instant(item_value)) => {
-                acc_value.append_instant(item_value, None, None)
-            }
+            (TInt::Sequence(acc_value), TInt::Instant(item_value)) => acc_value
+                .append_instant(item_value, None, None)
+                .expect("The instants must be in time order"),
+            (TInt::SequenceSet(acc_value), TInt::Instant(item_value)) => acc_value
+                .append_instant(item_value, None, None)
+                .expect("The instants must be in time order"),
             (TInt::SequenceSet(acc_value), TInt::Sequence(item_value)) => {
                 acc_value.append_sequence(item_value)
             }
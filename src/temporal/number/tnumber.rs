@@ -1,7 +1,19 @@
+use std::{ffi::c_void, ptr};
+
+use chrono::{DateTime, Utc};
+
 use crate::{
-    boxes::tbox::TBox,
-    collections::number::{number_span::NumberSpan, number_span_set::NumberSpanSet},
+    boxes::{r#box::Box as MeosBox, tbox::TBox},
+    collections::{
+        base::{span::Span, span_set::SpanSet},
+        datetime::tstz_span::TsTzSpan,
+        number::{
+            float_span::FloatSpan, float_span_set::FloatSpanSet, number_span::NumberSpan,
+            number_span_set::NumberSpanSet,
+        },
+    },
     temporal::temporal::Temporal,
+    utils::from_meos_timestamp,
 };
 
 pub trait TNumber: Temporal<TBB = TBox> {
@@ -39,25 +51,141 @@ pub trait TNumber: Temporal<TBB = TBox> {
         unsafe { meos_sys::tnumber_twavg(self.inner()) }
     }
 
+    /// Returns the value intervals that `self` passes through, as floats.
+    ///
+    /// For linear interpolation every segment contributes the whole range between its end values,
+    /// while for discrete and step interpolations only the values actually taken are included.
+    /// Ranges visited several times, e.g. by a value that rises and then falls back, are merged,
+    /// so this differs from the single span of the bounding box only when `self` skips values.
+    ///
+    /// # Returns
+    /// A `FloatSpanSet` with the values of `self`.
+    ///
+    /// # Safety
+    /// This function uses unsafe code to call the `meos_sys::tnumber_valuespans` and
+    /// `meos_sys::intspanset_to_floatspanset` functions.
+    fn value_span_set(&self) -> FloatSpanSet {
+        unsafe {
+            let spans = meos_sys::tnumber_valuespans(self.inner());
+            if (*spans).basetype == meos_sys::meosType_T_INT4 as u8 {
+                let float_spans = meos_sys::intspanset_to_floatspanset(spans);
+                libc::free(spans as *mut c_void);
+                FloatSpanSet::from_inner(float_spans)
+            } else {
+                FloatSpanSet::from_inner(spans)
+            }
+        }
+    }
+
+    /// Returns the range of values taken by `self` during the time window `window`, as floats.
+    ///
+    /// # Arguments
+    /// * `window` - The time window to restrict `self` to.
+    ///
+    /// # Returns
+    /// The `FloatSpan` of the values of `self` within `window`, or `None` if `self` is not
+    /// defined during `window`.
+    ///
+    /// # Safety
+    /// This function uses unsafe code to call the `meos_sys::temporal_at_tstzspan`,
+    /// `meos_sys::tnumber_to_span` and `meos_sys::intspan_to_floatspan` functions.
+    fn value_span_in_window(&self, window: &TsTzSpan) -> Option<FloatSpan> {
+        unsafe {
+            let restricted = meos_sys::temporal_at_tstzspan(self.inner(), window.inner());
+            if restricted.is_null() {
+                return None;
+            }
+            let mut span = meos_sys::tnumber_to_span(restricted);
+            libc::free(restricted as *mut c_void);
+            if (*span).basetype == meos_sys::meosType_T_INT4 as u8 {
+                let float_span = meos_sys::intspan_to_floatspan(span);
+                libc::free(span as *mut c_void);
+                span = float_span;
+            }
+            Some(FloatSpan::from_inner(span))
+        }
+    }
+
+    /// Returns the running bounding box of `self` after each of its instants, in time order.
+    ///
+    /// Every box encloses all the instants up to and including its timestamp, so each box is
+    /// contained in the next one and the last one is the bounding box of `self`.
+    ///
+    /// # Returns
+    /// A list of `(timestamp, bounding box)` pairs, one per instant.
+    ///
+    /// # Safety
+    /// This function uses unsafe code to call the `meos_sys::temporal_instants`,
+    /// `meos_sys::tnumber_to_tbox` and `meos_sys::union_tbox_tbox` functions.
+    fn expanding_bounds(&self) -> Vec<(DateTime<Utc>, TBox)> {
+        let mut count = 0;
+        let instants =
+            unsafe { meos_sys::temporal_instants(self.inner(), ptr::addr_of_mut!(count)) };
+        let mut bounds: Vec<(DateTime<Utc>, TBox)> = Vec::with_capacity(count as usize);
+        for i in 0..count as usize {
+            unsafe {
+                let instant = *instants.add(i);
+                let instant_box = TBox::from_inner(meos_sys::tnumber_to_tbox(
+                    instant as *const meos_sys::Temporal,
+                ));
+                let running = match bounds.last() {
+                    Some((_, previous)) => previous.union(&instant_box, false).unwrap(),
+                    None => instant_box,
+                };
+                bounds.push((from_meos_timestamp((*instant).t), running));
+            }
+        }
+        unsafe { libc::free(instants as *mut c_void) };
+        bounds
+    }
+
     // ------------------------- Restrictions ----------------------------------
+    /// Returns a new temporal object with the values of `self` where it's in `span`
+    ///
+    /// ## Arguments
+    /// * `span` - A `IntSpan` or `FloatSpan` with the values to keep, of the same base type as `self`
+    ///
+    /// ## Returns
+    /// The restricted temporal object, or `None` if `self` never takes a value in `span`
+    fn at_span(&self, span: &impl NumberSpan) -> Option<Self> {
+        let result = unsafe { meos_sys::tnumber_at_span(self.inner(), span.inner()) };
+        (!result.is_null()).then(|| Self::from_inner_as_temporal(result))
+    }
+
+    /// Returns a new temporal object with the values of `self` where it's in `span_set`
+    ///
+    /// ## Arguments
+    /// * `span_set` - A `IntSpanSet` or `FloatSpanSet` with the values to keep, of the same base type as `self`
+    ///
+    /// ## Returns
+    /// The restricted temporal object, or `None` if `self` never takes a value in `span_set`
+    fn at_span_set(&self, span_set: &impl NumberSpanSet) -> Option<Self> {
+        let result = unsafe { meos_sys::tnumber_at_spanset(self.inner(), span_set.inner()) };
+        (!result.is_null()).then(|| Self::from_inner_as_temporal(result))
+    }
+
     /// Returns a new temporal object with the values of `self` where it's not in `span`
     ///
     /// ## Arguments
     /// * `span` - A `IntSpan` or `FloatSpan` to substract the values from
-    fn minus_span(&self, span: impl NumberSpan) -> Self {
-        Self::from_inner_as_temporal(unsafe {
-            meos_sys::tnumber_minus_span(self.inner(), span.inner())
-        })
+    ///
+    /// ## Returns
+    /// The restricted temporal object, or `None` if `self` always takes a value in `span`
+    fn minus_span(&self, span: &impl NumberSpan) -> Option<Self> {
+        let result = unsafe { meos_sys::tnumber_minus_span(self.inner(), span.inner()) };
+        (!result.is_null()).then(|| Self::from_inner_as_temporal(result))
     }
 
     /// Returns a new temporal object with the values of `self` where it's not in `span_set`
     ///
     /// ## Arguments
     /// * `span_set` - A `IntSpanSet` or `FloatSpanSet` to substract the values from
-    fn minus_span_set(&self, span_set: impl NumberSpanSet) -> Self {
-        Self::from_inner_as_temporal(unsafe {
-            meos_sys::tnumber_minus_spanset(self.inner(), span_set.inner())
-        })
+    ///
+    /// ## Returns
+    /// The restricted temporal object, or `None` if `self` always takes a value in `span_set`
+    fn minus_span_set(&self, span_set: &impl NumberSpanSet) -> Option<Self> {
+        let result = unsafe { meos_sys::tnumber_minus_spanset(self.inner(), span_set.inner()) };
+        (!result.is_null()).then(|| Self::from_inner_as_temporal(result))
     }
 
     // ------------------------- Operations ------------------------------------
@@ -154,6 +282,7 @@ macro_rules! impl_temporal_for_tnumber {
                 }
             }
             impl_simple_traits_for_temporal!($type, with_drop);
+            impl_ordering_for_temporal!($type);
 
 
             impl TNumber for $type {
@@ -284,6 +413,74 @@ macro_rules! impl_temporal_for_tnumber {
     }
 }
 
+/// Implements the arithmetic operator `$trait` between `$type` and its base type on either
+/// side, and between two `$type`, which yields `None` when they don't share any timestamp.
+///
+/// ## Parameters:
+///    - `type`: The enum type to implement the operator for, `TInt` or `TFloat`
+///    - `base_type`: The base Rust type, i32 or f64.
+///    - `basic_type`: Whether it's Int or Float.
+///    - `trait`, `method`: The operator trait and its method.
+///    - `operation`: The prefix of the MEOS functions implementing the operation.
+macro_rules! impl_tnumber_operator {
+    ($type:ty, $base_type:ty, $basic_type:ident, $trait:ident, $method:ident, $operation:ident) => {
+        paste::paste! {
+            impl std::ops::$trait<$base_type> for &$type {
+                type Output = $type;
+
+                fn $method(self, rhs: $base_type) -> Self::Output {
+                    factory::<$type>(unsafe {
+                        meos_sys::[<$operation _t $basic_type:lower _ $basic_type:lower>](self.inner(), rhs)
+                    })
+                }
+            }
+
+            impl std::ops::$trait<$base_type> for $type {
+                type Output = $type;
+
+                fn $method(self, rhs: $base_type) -> Self::Output {
+                    std::ops::$trait::$method(&self, rhs)
+                }
+            }
+
+            impl std::ops::$trait<&$type> for $base_type {
+                type Output = $type;
+
+                fn $method(self, rhs: &$type) -> Self::Output {
+                    factory::<$type>(unsafe {
+                        meos_sys::[<$operation _ $basic_type:lower _t $basic_type:lower>](self, rhs.inner())
+                    })
+                }
+            }
+
+            impl std::ops::$trait<$type> for $base_type {
+                type Output = $type;
+
+                fn $method(self, rhs: $type) -> Self::Output {
+                    std::ops::$trait::$method(self, &rhs)
+                }
+            }
+
+            impl std::ops::$trait for &$type {
+                type Output = Option<$type>;
+
+                fn $method(self, rhs: Self) -> Self::Output {
+                    let result = unsafe { meos_sys::[<$operation _tnumber_tnumber>](self.inner(), rhs.inner()) };
+                    (!result.is_null()).then(|| factory::<$type>(result))
+                }
+            }
+
+            impl std::ops::$trait for $type {
+                type Output = Option<$type>;
+
+                fn $method(self, rhs: Self) -> Self::Output {
+                    std::ops::$trait::$method(&self, &rhs)
+                }
+            }
+        }
+    };
+}
+
 macro_rules! impl_meos_enum {
     ($type:ty, $base_type:ty, $basic_type:ident) => {
         paste::paste! {
@@ -296,6 +493,7 @@ macro_rules! impl_meos_enum {
             }
         }
         impl_simple_traits_for_temporal!($type);
+        impl_ordering_for_temporal!($type);
 
 
         impl TNumber for $type {
@@ -424,9 +622,84 @@ macro_rules! impl_meos_enum {
 
             impl_always_and_ever_value_equality_functions!([<$basic_type:lower>]);
         }
+
+        $crate::temporal::number::tnumber::impl_tnumber_operator!($type, $base_type, $basic_type, Add, add, add);
+        $crate::temporal::number::tnumber::impl_tnumber_operator!($type, $base_type, $basic_type, Sub, sub, sub);
+        $crate::temporal::number::tnumber::impl_tnumber_operator!($type, $base_type, $basic_type, Mul, mul, mult);
+
+        /// Divides `self` by a scalar, failing if `rhs` is zero.
+        impl std::ops::Div<$base_type> for &$type {
+            type Output = Result<$type, $crate::errors::MeosError>;
+
+            fn div(self, rhs: $base_type) -> Self::Output {
+                let (result, error) = $crate::capture_meos_error(|| unsafe {
+                    meos_sys::[<div_t $basic_type:lower _ $basic_type:lower>](self.inner(), rhs)
+                });
+                match error {
+                    Some(message) => Err($crate::errors::MeosError { message }),
+                    None => Ok(factory::<$type>(result)),
+                }
+            }
+        }
+
+        impl std::ops::Div<$base_type> for $type {
+            type Output = Result<$type, $crate::errors::MeosError>;
+
+            fn div(self, rhs: $base_type) -> Self::Output {
+                &self / rhs
+            }
+        }
+
+        /// Divides a scalar by `rhs`, failing if `rhs` is ever zero.
+        impl std::ops::Div<&$type> for $base_type {
+            type Output = Result<$type, $crate::errors::MeosError>;
+
+            fn div(self, rhs: &$type) -> Self::Output {
+                let (result, error) = $crate::capture_meos_error(|| unsafe {
+                    meos_sys::[<div_ $basic_type:lower _t $basic_type:lower>](self, rhs.inner())
+                });
+                match error {
+                    Some(message) => Err($crate::errors::MeosError { message }),
+                    None => Ok(factory::<$type>(result)),
+                }
+            }
+        }
+
+        impl std::ops::Div<$type> for $base_type {
+            type Output = Result<$type, $crate::errors::MeosError>;
+
+            fn div(self, rhs: $type) -> Self::Output {
+                self / &rhs
+            }
+        }
+
+        /// Divides `self` by `rhs` where both are defined, failing if `rhs` is ever zero
+        /// there. Yields `Ok(None)` when they don't share any timestamp.
+        impl std::ops::Div for &$type {
+            type Output = Result<Option<$type>, $crate::errors::MeosError>;
+
+            fn div(self, rhs: Self) -> Self::Output {
+                let (result, error) = $crate::capture_meos_error(|| unsafe {
+                    meos_sys::div_tnumber_tnumber(self.inner(), rhs.inner())
+                });
+                match error {
+                    Some(message) => Err($crate::errors::MeosError { message }),
+                    None => Ok((!result.is_null()).then(|| factory::<$type>(result))),
+                }
+            }
+        }
+
+        impl std::ops::Div for $type {
+            type Output = Result<Option<$type>, $crate::errors::MeosError>;
+
+            fn div(self, rhs: Self) -> Self::Output {
+                &self / &rhs
+            }
+        }
     }
     };
 }
 
 pub(crate) use impl_meos_enum;
 pub(crate) use impl_temporal_for_tnumber;
+pub(crate) use impl_tnumber_operator;
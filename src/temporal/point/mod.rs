@@ -5,9 +5,16 @@ pub mod tpoint;
 #[cfg(test)]
 #[serial_test::serial]
 mod tests {
-    use crate::meos_initialize;
+    use crate::{
+        boxes::stbox::STBox,
+        meos_initialize,
+        temporal::{temporal::Temporal, tinstant::TInstant},
+    };
 
     use super::*;
+    use chrono::{TimeZone, Utc};
+    use geos::Geom;
+    use tpoint::TPointTrait;
 
     #[test]
     fn instant_tgeompoint() {
@@ -74,4 +81,397 @@ mod tests {
             format!("SequenceSet({})", string.to_owned())
         );
     }
+
+    #[test]
+    fn geohash_tgeompoint() {
+        meos_initialize("UTC");
+        let result: tgeompoint::TGeomPoint =
+            "[SRID=4326;POINT(-5.6 42.6)@2018-01-01 08:00:00+00, SRID=4326;POINT(10.40744 57.64911)@2018-01-01 09:00:00+00]"
+                .parse()
+                .unwrap();
+        let geohashes = result.geohashes(11).unwrap();
+        assert!(geohashes[0].starts_with("ezs42"));
+        assert!(geohashes[1].starts_with("u4pruydqqvj"));
+
+        let planar: tgeompoint::TGeomPoint = "POINT(0 0)@2018-01-01 08:00:00+00".parse().unwrap();
+        assert!(planar.geohashes(5).is_err());
+    }
+
+    #[test]
+    fn densify_tgeompoint() {
+        meos_initialize("UTC");
+        let track: tgeompoint::TGeomPoint =
+            "[POINT(0 0)@2018-01-01 08:00:00+00, POINT(10 0)@2018-01-01 08:10:00+00]"
+                .parse()
+                .unwrap();
+        let densified = track.densify(3.0);
+        assert_eq!(densified.num_instants(), 5);
+        assert_eq!(densified.timespan(), track.timespan());
+        for pair in densified.instants().windows(2) {
+            assert!(pair[0].value().distance(&pair[1].value()).unwrap() < 3.0);
+        }
+    }
+
+    #[test]
+    fn average_speeds_tgeompoint() {
+        meos_initialize("UTC");
+        // A fast leg of 30 units and a slow leg of 6 units, each lasting one hour, separated
+        // by a one hour gap.
+        let trajectory: tgeompoint::TGeomPoint =
+            "{[POINT(0 0)@2018-01-01 08:00:00+00, POINT(30 0)@2018-01-01 09:00:00+00], \
+            [POINT(30 0)@2018-01-01 10:00:00+00, POINT(36 0)@2018-01-01 11:00:00+00]}"
+                .parse()
+                .unwrap();
+        let hour = 3600.0;
+        assert!((trajectory.average_speed() - 36.0 / (3.0 * hour)).abs() < 1e-9);
+        assert!((trajectory.time_weighted_average_speed() - 36.0 / (2.0 * hour)).abs() < 1e-9);
+        assert!(trajectory.time_weighted_average_speed() > trajectory.average_speed());
+    }
+
+    #[test]
+    fn at_dwithin_tgeompoint() {
+        meos_initialize("UTC");
+        let eastbound: tgeompoint::TGeomPoint =
+            "[POINT(0 0)@2018-01-01 08:00:00+00, POINT(10 0)@2018-01-01 08:10:00+00]"
+                .parse()
+                .unwrap();
+        let westbound: tgeompoint::TGeomPoint =
+            "[POINT(10 0)@2018-01-01 08:00:00+00, POINT(0 0)@2018-01-01 08:10:00+00]"
+                .parse()
+                .unwrap();
+        let close = eastbound.at_dwithin(&westbound, 2.0).unwrap();
+        let minute = |m| Utc.with_ymd_and_hms(2018, 1, 1, 8, m, 0).unwrap();
+        assert!(
+            (close.start_timestamp() - minute(4))
+                .num_milliseconds()
+                .abs()
+                <= 1
+        );
+        assert!((close.end_timestamp() - minute(6)).num_milliseconds().abs() <= 1);
+
+        let far: tgeompoint::TGeomPoint =
+            "[POINT(0 50)@2018-01-01 08:00:00+00, POINT(10 50)@2018-01-01 08:10:00+00]"
+                .parse()
+                .unwrap();
+        assert!(eastbound.at_dwithin(&far, 2.0).is_none());
+    }
+
+    #[test]
+    fn within_distance_and_intersects_tgeompoint() {
+        meos_initialize("UTC");
+        let eastbound: tgeompoint::TGeomPoint =
+            "[POINT(0 0)@2018-01-01 08:00:00+00, POINT(10 0)@2018-01-01 08:10:00+00]"
+                .parse()
+                .unwrap();
+        let westbound: tgeompoint::TGeomPoint =
+            "[POINT(10 0)@2018-01-01 08:00:00+00, POINT(0 0)@2018-01-01 08:10:00+00]"
+                .parse()
+                .unwrap();
+        let minute = |m| Utc.with_ymd_and_hms(2018, 1, 1, 8, m, 0).unwrap();
+
+        let within = eastbound.is_within_distance(&westbound, 2.0);
+        assert_eq!(within.value_at_timestamp(minute(2)), Some(false));
+        assert_eq!(within.value_at_timestamp(minute(5)), Some(true));
+        assert_eq!(within.value_at_timestamp(minute(8)), Some(false));
+
+        let intersects = eastbound.intersects(&westbound);
+        assert_eq!(intersects.value_at_timestamp(minute(4)), Some(false));
+        assert_eq!(intersects.value_at_timestamp(minute(5)), Some(true));
+
+        let center = geos::Geometry::new_from_wkt("POINT(5 0)").unwrap();
+        let near_center = eastbound.within_distance_of_geometry(&center, 1.0);
+        assert_eq!(near_center.value_at_timestamp(minute(3)), Some(false));
+        assert_eq!(near_center.value_at_timestamp(minute(5)), Some(true));
+    }
+
+    #[test]
+    fn coordinates_tgeompoint() {
+        meos_initialize("UTC");
+        let tpoint: tgeompoint::TGeomPoint =
+            "[POINT(0 5)@2018-01-01 08:00:00+00, POINT(10 5)@2018-01-01 08:10:00+00]"
+                .parse()
+                .unwrap();
+        let minute = |m| Utc.with_ymd_and_hms(2018, 1, 1, 8, m, 0).unwrap();
+        let x = tpoint.x();
+        for m in 0..=10 {
+            assert_eq!(x.value_at_timestamp(minute(m)), Some(m as f64));
+        }
+        assert_eq!(tpoint.y().values(), vec![5.0]);
+        assert!(!tpoint.has_z());
+        assert!(tpoint.z().is_none());
+
+        let climbing: tgeompoint::TGeomPoint =
+            "[POINT Z(0 0 100)@2018-01-01 08:00:00+00, POINT Z(0 0 200)@2018-01-01 08:10:00+00]"
+                .parse()
+                .unwrap();
+        assert!(climbing.has_z());
+        let altitude = climbing.z().unwrap();
+        assert_eq!(altitude.value_at_timestamp(minute(5)), Some(150.0));
+    }
+
+    #[test]
+    fn speed_and_length_square_tgeompoint() {
+        meos_initialize("UTC");
+        let square: tgeompoint::TGeomPoint = "[POINT(0 0)@2018-01-01 08:00:00+00, \
+            POINT(10 0)@2018-01-01 08:10:00+00, POINT(10 10)@2018-01-01 08:15:00+00, \
+            POINT(0 10)@2018-01-01 08:25:00+00, POINT(0 0)@2018-01-01 08:30:00+00]"
+            .parse()
+            .unwrap();
+        let minute = |m| Utc.with_ymd_and_hms(2018, 1, 1, 8, m, 0).unwrap();
+        assert_eq!(square.length(), 40.0);
+        assert_eq!(square.cumulative_length().end_value(), 40.0);
+        assert_eq!(
+            square.cumulative_length().value_at_timestamp(minute(15)),
+            Some(20.0)
+        );
+
+        let speed = square.speed();
+        assert!(speed.is_stepwise());
+        let (slow, fast) = (10.0 / 600.0, 10.0 / 300.0);
+        assert_eq!(speed.value_at_timestamp(minute(2)), Some(slow));
+        assert_eq!(speed.value_at_timestamp(minute(8)), Some(slow));
+        assert_eq!(speed.value_at_timestamp(minute(12)), Some(fast));
+        assert_eq!(speed.value_at_timestamp(minute(20)), Some(slow));
+        assert_eq!(speed.value_at_timestamp(minute(28)), Some(fast));
+
+        let azimuth = square.azimuth().unwrap();
+        assert_eq!(
+            azimuth.value_at_timestamp(minute(5)),
+            Some(std::f64::consts::FRAC_PI_2)
+        );
+        assert_eq!(azimuth.value_at_timestamp(minute(12)), Some(0.0));
+    }
+
+    #[test]
+    fn num_waypoints_tgeompoint() {
+        meos_initialize("UTC");
+        let zig_zag: tgeompoint::TGeomPoint = "[POINT(0 0)@2018-01-01 08:00:00+00, \
+            POINT(5 5)@2018-01-01 08:05:00+00, POINT(10 10)@2018-01-01 08:10:00+00, \
+            POINT(20 0)@2018-01-01 08:20:00+00, POINT(20 0)@2018-01-01 08:25:00+00, \
+            POINT(30 10)@2018-01-01 08:35:00+00, POINT(40 0)@2018-01-01 08:45:00+00]"
+            .parse()
+            .unwrap();
+        let quarter = std::f64::consts::FRAC_PI_4;
+        assert_eq!(zig_zag.num_waypoints(quarter), 3);
+        assert_eq!(zig_zag.num_waypoints(3.0 * quarter), 0);
+
+        let instant: tgeompoint::TGeomPoint = "POINT(0 0)@2018-01-01 08:00:00+00".parse().unwrap();
+        assert_eq!(instant.num_waypoints(0.0), 0);
+    }
+
+    #[test]
+    fn trajectory_and_traversed_tgeompoint() {
+        meos_initialize("UTC");
+        let tpoint: tgeompoint::TGeomPoint = "[POINT(0 0)@2018-01-01 08:00:00+00, \
+            POINT(10 0)@2018-01-01 08:10:00+00, POINT(10 10)@2018-01-01 08:20:00+00]"
+            .parse()
+            .unwrap();
+        let trajectory = tpoint.trajectory().unwrap();
+        assert_eq!(trajectory.geometry_type(), geos::GeometryTypes::LineString);
+        assert_eq!(trajectory.get_num_points().unwrap(), 3);
+
+        let traversed = tpoint.traversed().unwrap();
+        assert_eq!(traversed.geometry_type(), geos::GeometryTypes::MultiPoint);
+        assert_eq!(traversed.get_num_geometries().unwrap(), 3);
+    }
+
+    #[test]
+    fn still_at_tgeompoint() {
+        meos_initialize("UTC");
+        let mut position = geos::Geometry::new_from_wkt("POINT(3 4)").unwrap();
+        position.set_srid(3857);
+        let span = "[2018-01-01 08:00:00+00, 2018-01-01 10:00:00+00]"
+            .parse()
+            .unwrap();
+        let parked = tgeompoint::TGeomPoint::still_at(&position, span);
+        assert_eq!(parked.srid(), 3857);
+        assert_eq!(parked.duration(false), chrono::TimeDelta::hours(2));
+        assert_eq!(parked.speed().values(), vec![0.0]);
+        assert_eq!(parked.length(), 0.0);
+        let at_nine = parked
+            .value_at_timestamp(Utc.with_ymd_and_hms(2018, 1, 1, 9, 0, 0).unwrap())
+            .unwrap();
+        assert!(at_nine.equals(&position).unwrap());
+    }
+
+    #[test]
+    fn geometry_geography_conversion() {
+        meos_initialize("UTC");
+        let planar: tgeompoint::TGeomPoint =
+            "SRID=4326;[POINT(0 0)@2018-01-01 08:00:00+00, POINT(1 0)@2018-01-01 09:00:00+00]"
+                .parse()
+                .unwrap();
+        assert_eq!(planar.length(), 1.0);
+
+        let spherical = planar.to_geography();
+        // One degree of longitude along the equator is about 111 km
+        assert!((111_000.0..111_400.0).contains(&spherical.length()));
+        assert_eq!(spherical.to_geometry(), planar);
+    }
+
+    #[test]
+    fn split_by_grid_tgeompoint() {
+        meos_initialize("UTC");
+        let diagonal: tgeompoint::TGeomPoint =
+            "[POINT(0.2 0.4)@2018-01-01 08:00:00+00, POINT(2.8 1.6)@2018-01-01 09:00:00+00]"
+                .parse()
+                .unwrap();
+        let mut fragments = diagonal.split_by_grid(1.0, (0.0, 0.0));
+        fragments.sort_by_key(|(cell, _)| *cell);
+        let cells: Vec<_> = fragments.iter().map(|(cell, _)| *cell).collect();
+        assert_eq!(cells, vec![(0, 0), (1, 0), (1, 1), (2, 1)]);
+        let total: f64 = fragments
+            .iter()
+            .map(|(_, fragment)| fragment.length())
+            .sum();
+        assert!((total - diagonal.length()).abs() < 1e-9);
+    }
+
+    #[test]
+    fn split_by_grid_with_srid_tgeompoint() {
+        meos_initialize("UTC");
+        let diagonal: tgeompoint::TGeomPoint = "SRID=3857;[POINT(20 40)@2018-01-01 08:00:00+00, POINT(280 160)@2018-01-01 09:00:00+00]"
+            .parse()
+            .unwrap();
+        let mut fragments = diagonal.split_by_grid(100.0, (0.0, 0.0));
+        fragments.sort_by_key(|(cell, _)| *cell);
+        let cells: Vec<_> = fragments.iter().map(|(cell, _)| *cell).collect();
+        assert_eq!(cells, vec![(0, 0), (1, 0), (1, 1), (2, 1)]);
+        assert!(fragments
+            .iter()
+            .all(|(_, fragment)| fragment.srid() == 3857));
+    }
+
+    #[test]
+    fn iter_positions_tgeompoint() {
+        meos_initialize("UTC");
+        let trip: tgeompoint::TGeomPoint = "[POINT(0 0)@2018-01-01 08:00:00+00, POINT(1 1)@2018-01-01 08:10:00+00, POINT(2 0)@2018-01-01 08:20:00+00]"
+            .parse()
+            .unwrap();
+        let positions: Vec<_> = trip.iter_positions().collect();
+        assert_eq!(positions.len(), 3);
+        let coordinates: Vec<_> = positions
+            .iter()
+            .map(|(point, _)| (point.get_x().unwrap(), point.get_y().unwrap()))
+            .collect();
+        assert_eq!(coordinates, vec![(0.0, 0.0), (1.0, 1.0), (2.0, 0.0)]);
+        assert_eq!(positions[0].1, trip.start_timestamp());
+        assert_eq!(
+            positions[1].1,
+            Utc.with_ymd_and_hms(2018, 1, 1, 8, 10, 0).unwrap()
+        );
+        assert_eq!(positions[2].1, trip.end_timestamp());
+    }
+
+    #[test]
+    fn minimum_bounding_circle_tgeompoint() {
+        meos_initialize("UTC");
+        let square: tgeompoint::TGeomPoint = "[POINT(-1 -1)@2018-01-01 08:00:00+00, POINT(1 -1)@2018-01-01 08:10:00+00, POINT(1 1)@2018-01-01 08:20:00+00, POINT(-1 1)@2018-01-01 08:30:00+00, POINT(0 0)@2018-01-01 08:40:00+00]"
+            .parse()
+            .unwrap();
+        let (center, radius) = square.minimum_bounding_circle();
+        assert!(center.get_x().unwrap().abs() < 1e-9);
+        assert!(center.get_y().unwrap().abs() < 1e-9);
+        assert!((radius - 2f64.sqrt()).abs() < 1e-9);
+    }
+
+    #[test]
+    fn crossing_times_tgeompoint() {
+        meos_initialize("UTC");
+        let trip: tgeompoint::TGeomPoint =
+            "[POINT(0 0)@2018-01-01 08:00:00+00, POINT(10 0)@2018-01-01 08:10:00+00]"
+                .parse()
+                .unwrap();
+        let cordon = geos::Geometry::new_from_wkt("LINESTRING(4 -5, 4 5)").unwrap();
+        assert_eq!(
+            trip.crossing_times(&cordon),
+            vec![Utc.with_ymd_and_hms(2018, 1, 1, 8, 4, 0).unwrap()]
+        );
+        let away = geos::Geometry::new_from_wkt("LINESTRING(20 -5, 20 5)").unwrap();
+        assert!(trip.crossing_times(&away).is_empty());
+    }
+
+    #[test]
+    fn resample_by_distance_tgeompoint() {
+        meos_initialize("UTC");
+        let tpoint: tgeompoint::TGeomPoint =
+            "[POINT(0 0)@2018-01-01 08:00:00+00, POINT(30 0)@2018-01-01 08:00:30+00, POINT(100 0)@2018-01-01 08:01:40+00]"
+                .parse()
+                .unwrap();
+        let resampled = tpoint.resample_by_distance(10.0);
+        assert_eq!(resampled.num_instants(), 11);
+        let timestamps = resampled.timestamps();
+        assert!(timestamps.windows(2).all(|pair| pair[0] < pair[1]));
+        assert_eq!(resampled.start_timestamp(), tpoint.start_timestamp());
+        assert_eq!(resampled.end_timestamp(), tpoint.end_timestamp());
+    }
+
+    #[test]
+    fn combined_convex_hull_tgeompoint() {
+        meos_initialize("UTC");
+        let first: tgeompoint::TGeomPoint =
+            "[POINT(0 0)@2018-01-01 08:00:00+00, POINT(1 1)@2018-01-01 08:10:00+00]"
+                .parse()
+                .unwrap();
+        let second: tgeompoint::TGeomPoint =
+            "[POINT(10 0)@2018-01-01 08:00:00+00, POINT(10 5)@2018-01-01 08:10:00+00]"
+                .parse()
+                .unwrap();
+        let hull = tgeompoint::TGeomPoint::combined_convex_hull(&[first.clone(), second.clone()]);
+        for vertex in first.values().iter().chain(second.values().iter()) {
+            assert!(hull.covers(vertex).unwrap());
+        }
+        assert_eq!(hull.area().unwrap(), 27.5);
+    }
+
+    #[test]
+    fn bbox_intersects_tgeompoint() {
+        meos_initialize("UTC");
+        let tpoint: tgeompoint::TGeomPoint =
+            "[POINT(0 0)@2018-01-01 08:00:00+00, POINT(10 10)@2018-01-01 08:10:00+00]"
+                .parse()
+                .unwrap();
+        let corner: STBox = "STBOX X((8,0),(10,2))".parse().unwrap();
+        assert!(tpoint.bbox_intersects(&corner));
+        assert!(tpoint.at_stbox(&corner).is_none());
+
+        let diagonal: STBox = "STBOX X((4,4),(6,6))".parse().unwrap();
+        assert!(tpoint.bbox_intersects(&diagonal));
+        assert!(tpoint.at_stbox(&diagonal).is_some());
+
+        let outside: STBox = "STBOX X((20,20),(30,30))".parse().unwrap();
+        assert!(!tpoint.bbox_intersects(&outside));
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    #[serial_test::serial]
+    fn serde_roundtrip_tgeompoint() {
+        meos_initialize("UTC");
+        let tpoint: tgeompoint::TGeomPoint =
+            "SRID=3857;[POINT(1.123456789 2)@2020-01-01, POINT(3 4)@2020-01-02]"
+                .parse()
+                .unwrap();
+        let json = serde_json::to_string(&tpoint).unwrap();
+        let parsed: tgeompoint::TGeomPoint = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed, tpoint);
+        assert_eq!(parsed.srid(), 3857);
+    }
+
+    #[test]
+    #[serial_test::serial]
+    fn from_mfjson_tgeompoint() {
+        use crate::{temporal::JSONCVariant, MeosEnum};
+
+        meos_initialize("UTC");
+        let tpoint: tgeompoint::TGeomPoint = "[POINT(1 2)@2020-01-01, POINT(3 4)@2020-01-02]"
+            .parse()
+            .unwrap();
+        let mfjson = tpoint.as_mfjson(false, JSONCVariant::Plain, 15, "");
+        assert_eq!(
+            tgeompoint::TGeomPoint::from_mfjson(&mfjson).unwrap(),
+            tpoint
+        );
+        assert!(tgeompoint::TGeomPoint::from_mfjson(&mfjson[..mfjson.len() - 3]).is_err());
+    }
 }
@@ -1,6 +1,6 @@
-use crate::impl_from_str;
 use crate::temporal::interpolation::TInterpolation;
 use crate::temporal::{tinstant::TInstant, tsequence::TSequence};
+use crate::{impl_from_str, impl_serde};
 use core::fmt;
 use std::{
     ffi::{c_void, CString},
@@ -13,8 +13,8 @@ use crate::temporal::tsequence_set::TSequenceSet;
 use crate::{
     boxes::stbox::STBox,
     collections::base::collection::{impl_collection, Collection},
-    errors::ParseError,
-    factory,
+    errors::TemporalParseError,
+    factory, parse_mfjson,
     temporal::{
         tbool::*,
         temporal::{
@@ -28,6 +28,7 @@ use crate::{
 use chrono::{DateTime, TimeZone};
 use geos::Geometry;
 
+use super::tgeompoint::TGeomPoint;
 use super::tpoint::{
     create_set_of_geometries, geometry_to_gserialized, gserialized_to_geometry, impl_tpoint_traits,
     TPointTrait,
@@ -138,9 +139,28 @@ pub enum TGeogPoint {
 }
 
 impl_from_str!(TGeogPoint);
+impl_serde!(TGeogPoint, |temporal| unsafe {
+    meos_sys::tpoint_as_ewkt(temporal.inner(), 15)
+});
 
 impl TPointTrait<true> for TGeogPoint {}
 
+impl TGeogPoint {
+    /// Converts the temporal point to a geometric one, so that distances and lengths are
+    /// computed on the plane using the units of its SRID.
+    ///
+    /// The SRID is kept, so the result is usually in WGS 84 (4326) and measured in degrees.
+    ///
+    /// ## Returns
+    /// The equivalent `TGeomPoint`.
+    ///
+    /// MEOS Functions:
+    ///     `tgeogpoint_to_tgeompoint`
+    pub fn to_geometry(&self) -> TGeomPoint {
+        factory::<TGeomPoint>(unsafe { meos_sys::tgeogpoint_to_tgeompoint(self.inner()) })
+    }
+}
+
 impl MeosEnum for TGeogPoint {
     fn from_instant(inner: *mut meos_sys::TInstant) -> Self {
         Self::Instant(TGeogPointInstant::from_inner(inner))
@@ -154,9 +174,8 @@ impl MeosEnum for TGeogPoint {
         Self::SequenceSet(TGeogPointSequenceSet::from_inner(inner))
     }
 
-    fn from_mfjson(mfjson: &str) -> Self {
-        let cstr = CString::new(mfjson).unwrap();
-        factory::<Self>(unsafe { meos_sys::tint_from_mfjson(cstr.as_ptr()) })
+    fn from_mfjson(mfjson: &str) -> Result<Self, TemporalParseError> {
+        parse_mfjson(mfjson, meos_sys::tgeogpoint_from_mfjson)
     }
 }
 
@@ -1,6 +1,6 @@
-use crate::impl_from_str;
 use crate::temporal::interpolation::TInterpolation;
 use crate::temporal::{tinstant::TInstant, tsequence::TSequence};
+use crate::{impl_from_str, impl_serde};
 use core::fmt;
 use std::{
     ffi::{c_void, CString},
@@ -12,22 +12,31 @@ use std::{
 use crate::temporal::tsequence_set::TSequenceSet;
 use crate::{
     boxes::stbox::STBox,
-    collections::base::collection::{impl_collection, Collection},
-    errors::ParseError,
-    factory,
+    collections::{
+        base::{
+            collection::{impl_collection, Collection},
+            span::Span,
+            span_set::SpanSet,
+        },
+        datetime::{tstz_span::TsTzSpan, tstz_span_set::TsTzSpanSet},
+    },
+    errors::{SridError, TemporalParseError},
+    factory, parse_mfjson,
     temporal::{
+        number::tnumber::TNumber,
         tbool::*,
         temporal::{
             impl_always_and_ever_value_equality_functions, impl_simple_traits_for_temporal,
             SimplifiableTemporal, Temporal,
         },
     },
-    utils::to_meos_timestamp,
+    utils::{from_meos_timestamp, to_meos_timestamp},
     MeosEnum,
 };
-use chrono::{DateTime, TimeZone};
-use geos::Geometry;
+use chrono::{DateTime, TimeDelta, TimeZone, Utc};
+use geos::{Geom, Geometry};
 
+use super::tgeogpoint::TGeogPoint;
 use super::tpoint::{
     create_set_of_geometries, geometry_to_gserialized, gserialized_to_geometry, impl_tpoint_traits,
     TPointTrait,
@@ -62,6 +71,63 @@ impl TInstant for TGeomPointInstant {
 
 impl TPointTrait<false> for TGeomPointInstant {}
 
+const GEOHASH_BASE32: &[u8; 32] = b"0123456789bcdefghjkmnpqrstuvwxyz";
+
+/// Encodes a longitude/latitude pair as a geohash of `precision` characters.
+fn encode_geohash(longitude: f64, latitude: f64, precision: usize) -> String {
+    let (mut lon_range, mut lat_range) = ((-180.0, 180.0), (-90.0, 90.0));
+    let mut hash = String::with_capacity(precision);
+    let mut even_bit = true;
+    let (mut bits, mut index) = (0, 0);
+    while hash.len() < precision {
+        let (range, value) = if even_bit {
+            (&mut lon_range, longitude)
+        } else {
+            (&mut lat_range, latitude)
+        };
+        let middle = (range.0 + range.1) / 2.0;
+        index <<= 1;
+        if value >= middle {
+            index |= 1;
+            range.0 = middle;
+        } else {
+            range.1 = middle;
+        }
+        even_bit = !even_bit;
+        bits += 1;
+        if bits == 5 {
+            hash.push(GEOHASH_BASE32[index] as char);
+            (bits, index) = (0, 0);
+        }
+    }
+    hash
+}
+
+impl TGeomPointInstant {
+    /// Returns the geohash of the position of the instant.
+    ///
+    /// ## Arguments
+    /// * `precision` - Number of characters of the resulting geohash.
+    ///
+    /// ## Returns
+    /// The geohash, or a `SridError` if the point is not in WGS 84 (SRID 4326).
+    pub fn geohash(&self, precision: usize) -> Result<String, SridError> {
+        let srid = self.srid();
+        if srid != 4326 {
+            return Err(SridError {
+                expected: 4326,
+                found: srid,
+            });
+        }
+        let point = self.value();
+        Ok(encode_geohash(
+            point.get_x().unwrap(),
+            point.get_y().unwrap(),
+            precision,
+        ))
+    }
+}
+
 pub struct TGeomPointSequence {
     _inner: ptr::NonNull<meos_sys::TSequence>,
 }
@@ -80,6 +146,134 @@ impl TGeomPointSequence {
         let _ = unsafe { meos_sys::tpoint_direction(self.inner(), ptr::addr_of_mut!(result)) };
         result
     }
+
+    /// Returns a copy of the sequence with interpolated instants inserted so that no linear
+    /// segment is longer than `max_segment_length`.
+    ///
+    /// Sequences that are not linearly interpolated are returned unchanged.
+    ///
+    /// ## Arguments
+    /// * `max_segment_length` - Maximum distance between consecutive positions, in the units of the SRID.
+    ///
+    /// ## Returns
+    /// The densified `TGeomPointSequence`.
+    ///
+    /// ## Panics
+    /// If `max_segment_length` is not positive.
+    pub fn densify(&self, max_segment_length: f64) -> Self {
+        assert!(
+            max_segment_length > 0.0,
+            "The maximum segment length must be positive"
+        );
+        let instants = self.instants();
+        if self.interpolation() != TInterpolation::Linear || instants.len() < 2 {
+            return self.clone();
+        }
+        let mut densified = Vec::with_capacity(instants.len());
+        for segment in instants.windows(2) {
+            let (start, end) = (&segment[0], &segment[1]);
+            let length = start.value().distance(&end.value()).unwrap();
+            let pieces = (length / max_segment_length).ceil().max(1.0) as i32;
+            let (start_time, step) = (
+                start.timestamp(),
+                (end.timestamp() - start.timestamp()) / pieces,
+            );
+            densified.push(start.clone());
+            for piece in 1..pieces {
+                let timestamp = start_time + step * piece;
+                let position = self.value_at_timestamp(timestamp).unwrap();
+                densified.push(TGeomPointInstant::from_value_and_timestamp(
+                    position, timestamp,
+                ));
+            }
+        }
+        densified.push(instants.last().unwrap().clone());
+
+        let mut t_list: Vec<_> = densified.iter().map(TInstant::inner_as_tinstant).collect();
+        Self::from_inner(unsafe {
+            meos_sys::tsequence_make(
+                t_list.as_mut_ptr(),
+                t_list.len() as i32,
+                meos_sys::temporal_lower_inc(self.inner()),
+                meos_sys::temporal_upper_inc(self.inner()),
+                TInterpolation::Linear as u32,
+                false,
+            )
+        })
+    }
+
+    /// Returns the sequence resampled at positions evenly spaced by `step` along its path,
+    /// starting at its first position and ending at its last one.
+    ///
+    /// The timestamp of every new position is interpolated within the segment it lies on.
+    /// Sequences that are not linearly interpolated are returned unchanged.
+    ///
+    /// ## Arguments
+    /// * `step` - Distance between consecutive positions along the path, in the units of the SRID.
+    ///
+    /// ## Returns
+    /// The resampled `TGeomPointSequence`.
+    ///
+    /// ## Panics
+    /// If `step` is not positive.
+    pub fn resample_by_distance(&self, step: f64) -> Self {
+        assert!(step > 0.0, "The step must be positive");
+        let instants: Vec<TGeomPointInstant> = unsafe {
+            let mut count = 0;
+            let instants = meos_sys::temporal_instants(self.inner(), ptr::addr_of_mut!(count));
+            let copies = std::slice::from_raw_parts(instants, count as usize)
+                .iter()
+                .map(|&instant| {
+                    TGeomPointInstant::from_inner(meos_sys::temporal_copy(
+                        instant as *const meos_sys::Temporal,
+                    ) as *mut _)
+                })
+                .collect();
+            libc::free(instants as *mut c_void);
+            copies
+        };
+        if self.interpolation() != TInterpolation::Linear || instants.len() < 2 {
+            return self.clone();
+        }
+        let mut resampled = vec![instants[0].clone()];
+        let (mut travelled, mut next) = (0.0, step);
+        for segment in instants.windows(2) {
+            let (start, end) = (&segment[0], &segment[1]);
+            let length = start.value().distance(&end.value()).unwrap();
+            let duration = (end.timestamp() - start.timestamp())
+                .num_microseconds()
+                .unwrap_or(i64::MAX) as f64;
+            while next <= travelled + length {
+                let fraction = (next - travelled) / length;
+                let timestamp = start.timestamp()
+                    + TimeDelta::microseconds((duration * fraction).round() as i64);
+                if timestamp > resampled.last().unwrap().timestamp() {
+                    let position = self.value_at_timestamp(timestamp).unwrap();
+                    resampled.push(TGeomPointInstant::from_value_and_timestamp(
+                        position, timestamp,
+                    ));
+                }
+                next += step;
+            }
+            travelled += length;
+        }
+        let last = instants.last().unwrap();
+        if last.timestamp() > resampled.last().unwrap().timestamp() {
+            resampled.push(last.clone());
+        }
+
+        let mut t_list: Vec<_> = resampled.iter().map(TInstant::inner_as_tinstant).collect();
+        Self::from_inner(unsafe {
+            meos_sys::tsequence_make(
+                t_list.as_mut_ptr(),
+                t_list.len() as i32,
+                meos_sys::temporal_lower_inc(self.inner()),
+                meos_sys::temporal_upper_inc(self.inner()),
+                TInterpolation::Linear as u32,
+                false,
+            )
+        })
+    }
 }
 
 impl_tpoint_traits!(TGeomPointSequence, Sequence, false, Geom);
@@ -138,9 +332,470 @@ pub enum TGeomPoint {
 }
 
 impl_from_str!(TGeomPoint);
+impl_serde!(TGeomPoint, |temporal| unsafe {
+    meos_sys::tpoint_as_ewkt(temporal.inner(), 15)
+});
 
 impl TPointTrait<false> for TGeomPoint {}
 
+impl TGeomPoint {
+    /// Converts the temporal point to a geographic one, so that distances and lengths are
+    /// computed on the spheroid instead of on the plane.
+    ///
+    /// Coordinates are reinterpreted as longitude/latitude, so the point is expected to be in
+    /// a geographic SRID, typically WGS 84 (4326).
+    ///
+    /// ## Returns
+    /// The equivalent `TGeogPoint`.
+    ///
+    /// MEOS Functions:
+    ///     `tgeompoint_to_tgeogpoint`
+    pub fn to_geography(&self) -> TGeogPoint {
+        factory::<TGeogPoint>(unsafe { meos_sys::tgeompoint_to_tgeogpoint(self.inner()) })
+    }
+
+    /// Creates a temporal point that stays at `geometry` during the whole `span`, e.g. to model
+    /// a parked vehicle.
+    ///
+    /// ## Arguments
+    /// * `geometry` - The position of the point, whose SRID is kept.
+    /// * `span` - The time span during which the point stays there.
+    ///
+    /// ## Returns
+    /// A linear `TGeomPoint` sequence.
+    ///
+    /// MEOS Functions:
+    ///     `tpointseq_from_base_tstzspan`
+    pub fn still_at(geometry: &Geometry, span: TsTzSpan) -> TGeomPoint {
+        let geo = geometry_to_gserialized(geometry);
+        let sequence = unsafe {
+            let sequence = meos_sys::tpointseq_from_base_tstzspan(
+                geo,
+                span.inner(),
+                TInterpolation::Linear as u32,
+            );
+            libc::free(geo as *mut c_void);
+            sequence
+        };
+        TGeomPoint::Sequence(TGeomPointSequence::from_inner(sequence))
+    }
+
+    /// Returns an iterator over the position and timestamp of every instant of the temporal
+    /// point, in time order.
+    ///
+    /// The geometries are copied out of the temporal point, so they can outlive it.
+    ///
+    /// ## Returns
+    /// An iterator of `(point, timestamp)` pairs.
+    ///
+    /// MEOS Functions:
+    ///     `temporal_instants`, `tpoint_start_value`
+    pub fn iter_positions(&self) -> impl Iterator<Item = (Geometry, DateTime<Utc>)> {
+        let mut count = 0;
+        let positions: Vec<_> = unsafe {
+            let instants = meos_sys::temporal_instants(self.inner(), ptr::addr_of_mut!(count));
+            let positions = std::slice::from_raw_parts(instants, count as usize)
+                .iter()
+                .map(|&instant| {
+                    let value = meos_sys::tpoint_start_value(instant as *const meos_sys::Temporal);
+                    let geometry = gserialized_to_geometry(value).unwrap();
+                    libc::free(value as *mut c_void);
+                    (geometry, from_meos_timestamp((*instant).t))
+                })
+                .collect();
+            libc::free(instants as *mut c_void);
+            positions
+        };
+        positions.into_iter()
+    }
+
+    /// Returns the timestamps at which the temporal point crosses `line`, e.g. a cordon used
+    /// for traffic counting.
+    ///
+    /// When the point moves along `line` for a while, the time at which it reaches it is
+    /// returned.
+    ///
+    /// ## Arguments
+    /// * `line` - The line to cross, with the same SRID as the temporal point.
+    ///
+    /// ## Returns
+    /// The crossing timestamps in time order, empty if the point never intersects `line`.
+    ///
+    /// MEOS Functions:
+    ///     `tintersects_tpoint_geo`, `tbool_when_true`
+    pub fn crossing_times(&self, line: &Geometry) -> Vec<DateTime<Utc>> {
+        let geo = geometry_to_gserialized(line);
+        let when_true = unsafe {
+            let intersects = meos_sys::tintersects_tpoint_geo(self.inner(), geo, false, false);
+            libc::free(geo as *mut c_void);
+            if intersects.is_null() {
+                return Vec::new();
+            }
+            let when_true = meos_sys::tbool_when_true(intersects);
+            libc::free(intersects as *mut c_void);
+            when_true
+        };
+        if when_true.is_null() {
+            return Vec::new();
+        }
+        let periods = TsTzSpanSet::from_inner(when_true);
+        (1..=periods.num_spans())
+            .map(|n| periods.span_n(n).lower())
+            .collect()
+    }
+
+    /// Returns whether the bounding box of the temporal point overlaps `query`.
+    ///
+    /// Only the precomputed bounding box is compared, so this is a cheap pre-filter before
+    /// restricting the point with `at_stbox`: a `false` result guarantees that the point is
+    /// never inside `query`, but a `true` result doesn't guarantee that it ever is.
+    ///
+    /// ## Arguments
+    /// * `query` - The box to test, with the same SRID as the temporal point.
+    ///
+    /// MEOS Functions:
+    ///     `tpoint_to_stbox`, `overlaps_stbox_stbox`
+    pub fn bbox_intersects(&self, query: &STBox) -> bool {
+        unsafe {
+            let bbox = meos_sys::tpoint_to_stbox(self.inner());
+            let result = meos_sys::overlaps_stbox_stbox(bbox, query.inner());
+            libc::free(bbox as *mut c_void);
+            result
+        }
+    }
+
+    /// Returns the average speed of the temporal point, in units of the SRID per second.
+    ///
+    /// This is the length of the trajectory divided by the elapsed time between its first and
+    /// last instants, so periods where the point is not defined (gaps between sequences) count
+    /// as time spent without moving. See `time_weighted_average_speed` for an average that only
+    /// considers the periods where the point is defined.
+    ///
+    /// ## Returns
+    /// The average speed, or `0.0` for an instantaneous point.
+    ///
+    /// MEOS Functions:
+    ///     `tpoint_length`, `temporal_duration`
+    pub fn average_speed(&self) -> f64 {
+        let seconds = self.duration(true).num_milliseconds() as f64 / 1000.0;
+        if seconds == 0.0 {
+            return 0.0;
+        }
+        self.length() / seconds
+    }
+
+    /// Returns the time-weighted average of the speed of the temporal point, in units of the
+    /// SRID per second.
+    ///
+    /// Unlike `average_speed`, gaps between sequences are ignored: each sequence contributes
+    /// its speed weighted by its own duration only.
+    ///
+    /// ## Returns
+    /// The time-weighted average speed, or `0.0` for an instantaneous point.
+    ///
+    /// MEOS Functions:
+    ///     `tpoint_speed`, `tnumber_twavg`
+    pub fn time_weighted_average_speed(&self) -> f64 {
+        if self.duration(false).is_zero() {
+            return 0.0;
+        }
+        self.speed().time_weighted_average()
+    }
+
+    /// Returns the number of waypoints of the temporal point, that is, the instants where it
+    /// turns by more than `angle_threshold`. This summarizes the complexity of its path.
+    ///
+    /// Turning angles are computed in two dimensions, ignoring Z, between the headings of
+    /// consecutive segments of each sequence, skipping the instants where the point stays still.
+    ///
+    /// ## Arguments
+    /// * `angle_threshold` - Minimum turning angle of a waypoint, in radians between 0 and π.
+    ///
+    /// ## Returns
+    /// The number of waypoints, `0` for an instantaneous point.
+    ///
+    /// MEOS Functions:
+    ///     `temporal_sequences`, `temporal_instants`
+    pub fn num_waypoints(&self, angle_threshold: f64) -> usize {
+        if let TGeomPoint::Instant(_) = self {
+            return 0;
+        }
+        self.defined_sequences()
+            .into_iter()
+            .map(|sequence| {
+                let mut positions: Vec<(f64, f64)> = TGeomPoint::Sequence(sequence)
+                    .iter_positions()
+                    .map(|(point, _)| (point.get_x().unwrap(), point.get_y().unwrap()))
+                    .collect();
+                positions.dedup();
+                turning_angles(&positions)
+                    .filter(|angle| *angle > angle_threshold)
+                    .count()
+            })
+            .sum()
+    }
+
+    /// Returns the smallest circle enclosing the whole trajectory of the temporal point.
+    ///
+    /// The circle is computed in two dimensions, ignoring Z, with Welzl's algorithm over the
+    /// distinct positions of the point, which are the vertices of its trajectory.
+    ///
+    /// ## Returns
+    /// The center of the circle, with the SRID of the temporal point, and its radius in the
+    /// units of the SRID.
+    ///
+    /// MEOS Functions:
+    ///     `tpoint_values`
+    pub fn minimum_bounding_circle(&self) -> (Geometry, f64) {
+        let positions: Vec<(f64, f64)> = self
+            .values()
+            .iter()
+            .map(|point| (point.get_x().unwrap(), point.get_y().unwrap()))
+            .collect();
+        let ((x, y), radius) = enclosing_circle(&positions);
+        let mut center = Geometry::new_from_wkt(&format!("POINT({x} {y})")).unwrap();
+        center.set_srid(self.srid() as usize);
+        (center, radius)
+    }
+
+    /// Returns the convex hull of the trajectories of all the temporal points in `items`, e.g. to
+    /// display the extent of a fleet.
+    ///
+    /// ## Arguments
+    /// * `items` - The temporal points, all with the same SRID.
+    ///
+    /// ## Returns
+    /// The convex hull of the positions of all the points, with their SRID, or an empty
+    /// geometry collection if `items` is empty.
+    ///
+    /// MEOS Functions:
+    ///     `tpoint_values`
+    pub fn combined_convex_hull(items: &[TGeomPoint]) -> Geometry {
+        let Some(first) = items.first() else {
+            return Geometry::create_empty_collection(geos::GeometryTypes::GeometryCollection)
+                .unwrap();
+        };
+        let positions: Vec<Geometry> = items.iter().flat_map(|item| item.values()).collect();
+        let mut hull = Geometry::create_multipoint(positions)
+            .and_then(|points| points.convex_hull())
+            .unwrap();
+        hull.set_srid(first.srid() as usize);
+        hull
+    }
+
+    /// Returns a copy of the temporal point with interpolated instants inserted so that no linear
+    /// segment is longer than `max_segment_length`. Useful to smooth coarse tracks for display.
+    ///
+    /// ## Arguments
+    /// * `max_segment_length` - Maximum distance between consecutive positions, in the units of the SRID.
+    ///
+    /// ## Returns
+    /// The densified `TGeomPoint`.
+    ///
+    /// ## Panics
+    /// If `max_segment_length` is not positive.
+    pub fn densify(&self, max_segment_length: f64) -> Self {
+        assert!(
+            max_segment_length > 0.0,
+            "The maximum segment length must be positive"
+        );
+        match self {
+            TGeomPoint::Instant(_) => self.clone(),
+            TGeomPoint::Sequence(sequence) => {
+                TGeomPoint::Sequence(sequence.densify(max_segment_length))
+            }
+            TGeomPoint::SequenceSet(_) => {
+                let mut count = 0;
+                let sequences: Vec<TGeomPointSequence> = unsafe {
+                    let sequences =
+                        meos_sys::temporal_sequences(self.inner(), ptr::addr_of_mut!(count));
+                    Vec::from_raw_parts(sequences, count as usize, count as usize)
+                        .into_iter()
+                        .map(TGeomPointSequence::from_inner)
+                        .map(|sequence| sequence.densify(max_segment_length))
+                        .collect()
+                };
+                TGeomPoint::SequenceSet(TGeomPointSequenceSet::new(&sequences, false))
+            }
+        }
+    }
+
+    /// Returns the temporal point resampled at positions evenly spaced by `step` along its path.
+    /// Useful to preprocess tracks for map matching.
+    ///
+    /// Every sequence is resampled on its own, see `TGeomPointSequence::resample_by_distance`.
+    ///
+    /// ## Arguments
+    /// * `step` - Distance between consecutive positions along the path, in the units of the SRID.
+    ///
+    /// ## Returns
+    /// The resampled `TGeomPoint`.
+    ///
+    /// ## Panics
+    /// If `step` is not positive.
+    pub fn resample_by_distance(&self, step: f64) -> Self {
+        match self {
+            TGeomPoint::Instant(_) => self.clone(),
+            TGeomPoint::Sequence(sequence) => {
+                TGeomPoint::Sequence(sequence.resample_by_distance(step))
+            }
+            TGeomPoint::SequenceSet(_) => {
+                let mut count = 0;
+                let sequences: Vec<TGeomPointSequence> = unsafe {
+                    let sequences =
+                        meos_sys::temporal_sequences(self.inner(), ptr::addr_of_mut!(count));
+                    Vec::from_raw_parts(sequences, count as usize, count as usize)
+                        .into_iter()
+                        .map(TGeomPointSequence::from_inner)
+                        .map(|sequence| sequence.resample_by_distance(step))
+                        .collect()
+                };
+                TGeomPoint::SequenceSet(TGeomPointSequenceSet::new(&sequences, false))
+            }
+        }
+    }
+
+    /// Splits the temporal point into the fragments inside each cell of a regular square grid.
+    ///
+    /// Cells are identified by their column and row, counted from `origin`, so the cell
+    /// `(i, j)` spans `[origin.0 + i * cell_size, origin.0 + (i + 1) * cell_size)` horizontally,
+    /// and likewise vertically.
+    ///
+    /// ## Arguments
+    /// * `cell_size` - Width and height of every cell, in the units of the SRID.
+    /// * `origin` - Coordinates of the corner of the cell `(0, 0)`.
+    ///
+    /// ## Returns
+    /// The fragment of the temporal point inside every cell it traverses, keyed by cell.
+    ///
+    /// MEOS Functions:
+    ///     `tpoint_space_split`
+    pub fn split_by_grid(
+        &self,
+        cell_size: f64,
+        origin: (f64, f64),
+    ) -> Vec<((i64, i64), TGeomPoint)> {
+        let mut origin_point =
+            Geometry::new_from_wkt(&format!("POINT({} {})", origin.0, origin.1)).unwrap();
+        origin_point.set_srid(self.srid() as usize);
+        let sorigin = geometry_to_gserialized(&origin_point);
+        let mut bins: *mut *mut meos_sys::GSERIALIZED = ptr::null_mut();
+        let mut count = 0;
+        let fragments = unsafe {
+            meos_sys::tpoint_space_split(
+                self.inner(),
+                cell_size,
+                cell_size,
+                cell_size,
+                sorigin,
+                false,
+                false,
+                ptr::addr_of_mut!(bins),
+                ptr::addr_of_mut!(count),
+            )
+        };
+        unsafe { libc::free(sorigin as *mut c_void) };
+        if fragments.is_null() {
+            return Vec::new();
+        }
+        unsafe {
+            let fragments = Vec::from_raw_parts(fragments, count as usize, count as usize);
+            let bins = Vec::from_raw_parts(bins, count as usize, count as usize);
+            fragments
+                .into_iter()
+                .zip(bins)
+                .map(|(fragment, bin)| {
+                    let corner = gserialized_to_geometry(bin).unwrap();
+                    libc::free(bin as *mut c_void);
+                    let column = ((corner.get_x().unwrap() - origin.0) / cell_size).round();
+                    let row = ((corner.get_y().unwrap() - origin.1) / cell_size).round();
+                    ((column as i64, row as i64), factory::<TGeomPoint>(fragment))
+                })
+                .collect()
+        }
+    }
+
+    /// Returns the geohash of every instant of the temporal point.
+    ///
+    /// ## Arguments
+    /// * `precision` - Number of characters of each geohash.
+    ///
+    /// ## Returns
+    /// The geohashes in instant order, or a `SridError` if the point is not in WGS 84 (SRID 4326).
+    pub fn geohashes(&self, precision: usize) -> Result<Vec<String>, SridError> {
+        self.instants()
+            .iter()
+            .map(|instant| instant.geohash(precision))
+            .collect()
+    }
+}
+
+/// Returns the absolute turning angle, between 0 and π, at every inner vertex of the path
+/// going through `positions`, which must not repeat consecutively.
+fn turning_angles(positions: &[(f64, f64)]) -> impl Iterator<Item = f64> + '_ {
+    positions.windows(3).map(|window| {
+        let heading = |(ax, ay): (f64, f64), (bx, by): (f64, f64)| (by - ay).atan2(bx - ax);
+        let turn = (heading(window[1], window[2]) - heading(window[0], window[1]))
+            .rem_euclid(std::f64::consts::TAU);
+        turn.min(std::f64::consts::TAU - turn)
+    })
+}
+
+type Circle = ((f64, f64), f64);
+
+/// Computes the smallest circle enclosing `points` with the iterative form of Welzl's algorithm.
+fn enclosing_circle(points: &[(f64, f64)]) -> Circle {
+    let contains = |((cx, cy), radius): Circle, (x, y): (f64, f64)| {
+        (x - cx).hypot(y - cy) <= radius * (1.0 + 1e-12) + 1e-12
+    };
+    let mut circle = ((0.0, 0.0), 0.0);
+    for i in 0..points.len() {
+        if i > 0 && contains(circle, points[i]) {
+            continue;
+        }
+        circle = (points[i], 0.0);
+        for j in 0..i {
+            if contains(circle, points[j]) {
+                continue;
+            }
+            circle = circle_from_diameter(points[i], points[j]);
+            for k in 0..j {
+                if !contains(circle, points[k]) {
+                    circle = circumcircle(points[i], points[j], points[k]);
+                }
+            }
+        }
+    }
+    circle
+}
+
+fn circle_from_diameter((ax, ay): (f64, f64), (bx, by): (f64, f64)) -> Circle {
+    (
+        ((ax + bx) / 2.0, (ay + by) / 2.0),
+        (ax - bx).hypot(ay - by) / 2.0,
+    )
+}
+
+fn circumcircle(a: (f64, f64), b: (f64, f64), c: (f64, f64)) -> Circle {
+    let (bx, by) = (b.0 - a.0, b.1 - a.1);
+    let (cx, cy) = (c.0 - a.0, c.1 - a.1);
+    let d = 2.0 * (bx * cy - by * cx);
+    if d.abs() < f64::EPSILON {
+        // Collinear points: the circle spans the two farthest ones
+        return [
+            circle_from_diameter(a, b),
+            circle_from_diameter(a, c),
+            circle_from_diameter(b, c),
+        ]
+        .into_iter()
+        .max_by(|left, right| left.1.total_cmp(&right.1))
+        .unwrap();
+    }
+    let (b2, c2) = (bx * bx + by * by, cx * cx + cy * cy);
+    let (ux, uy) = ((cy * b2 - by * c2) / d, (bx * c2 - cx * b2) / d);
+    ((a.0 + ux, a.1 + uy), ux.hypot(uy))
+}
+
 impl MeosEnum for TGeomPoint {
     fn from_instant(inner: *mut meos_sys::TInstant) -> Self {
         Self::Instant(TGeomPointInstant::from_inner(inner))
@@ -154,9 +809,8 @@ impl MeosEnum for TGeomPoint {
         Self::SequenceSet(TGeomPointSequenceSet::from_inner(inner))
     }
 
-    fn from_mfjson(mfjson: &str) -> Self {
-        let cstr = CString::new(mfjson).unwrap();
-        factory::<Self>(unsafe { meos_sys::tint_from_mfjson(cstr.as_ptr()) })
+    fn from_mfjson(mfjson: &str) -> Result<Self, TemporalParseError> {
+        parse_mfjson(mfjson, meos_sys::tgeompoint_from_mfjson)
     }
 }
 
@@ -26,7 +26,7 @@ fn point_to_gserialize(point: Point, geodetic: bool) -> *mut meos_sys::GSERIALIZ
     }
 }
 
-pub(super) fn geometry_to_gserialized(geometry: &Geometry) -> *mut GSERIALIZED {
+pub(crate) fn geometry_to_gserialized(geometry: &Geometry) -> *mut GSERIALIZED {
     let mut writer = WKBWriter::new().expect("Failed to create WKBWriter");
     writer.set_output_dimension(OutputDimension::ThreeD);
     let wkb: Vec<u8> = writer.write_wkb(geometry).unwrap().into();
@@ -138,6 +138,9 @@ pub trait TPointTrait<const IS_GEODETIC: bool>: Temporal {
 
     /// Returns the length of the trajectory.
     ///
+    /// The length is in the units of the SRID for geometric points and in meters for
+    /// geographic points.
+    ///
     /// ## Returns
     ///
     /// A `f64` with the length of the trajectory.
@@ -149,7 +152,7 @@ pub trait TPointTrait<const IS_GEODETIC: bool>: Temporal {
         unsafe { meos_sys::tpoint_length(self.inner()) }
     }
 
-    /// Returns the cumulative length of the trajectory.
+    /// Returns the cumulative length of the trajectory, in the same units as `length`.
     ///
     /// ## Returns
     ///
@@ -164,6 +167,10 @@ pub trait TPointTrait<const IS_GEODETIC: bool>: Temporal {
 
     /// Returns the speed of the temporal point.
     ///
+    /// The speed is in units of the SRID per second for geometric points and in meters per
+    /// second for geographic points. It is constant along each segment, so the result has
+    /// stepwise interpolation.
+    ///
     /// ## Returns
     ///
     /// A `TFloat` with the speed of the temporal point.
@@ -228,10 +235,14 @@ pub trait TPointTrait<const IS_GEODETIC: bool>: Temporal {
     ///
     /// ## MEOS Functions
     ///
-    /// tpoint_start_value
+    /// tpoint_to_stbox, stbox_hasz
     fn has_z(&self) -> bool {
-        let ptr = unsafe { meos_sys::tpoint_get_z(self.inner()) };
-        !ptr.is_null()
+        unsafe {
+            let bbox = meos_sys::tpoint_to_stbox(self.inner());
+            let result = meos_sys::stbox_hasz(bbox);
+            libc::free(bbox as *mut c_void);
+            result
+        }
     }
 
     /// Returns a collection of bounding boxes representing the segments of the temporal point.
@@ -248,14 +259,12 @@ pub trait TPointTrait<const IS_GEODETIC: bool>: Temporal {
         let result = unsafe { meos_sys::tpoint_stboxes(self.inner(), ptr::addr_of_mut!(count)) };
 
         unsafe {
-            Vec::from_raw_parts(result, count as usize, count as usize)
+            let boxes = std::slice::from_raw_parts(result, count as usize)
                 .iter()
-                .map(|&stbox| {
-                    let mut boxed_stbox = Box::new(stbox);
-                    let ptr: *mut meos_sys::STBox = &mut *boxed_stbox;
-                    STBox::from_inner(ptr)
-                })
-                .collect()
+                .map(|stbox| STBox::from_inner(meos_sys::stbox_copy(stbox)))
+                .collect();
+            libc::free(result as *mut c_void);
+            boxes
         }
     }
 
@@ -307,11 +316,12 @@ pub trait TPointTrait<const IS_GEODETIC: bool>: Temporal {
         factory::<TFloat>(unsafe { meos_sys::bearing_tpoint_point(self.inner(), geo, false) })
     }
 
-    /// Returns the temporal azimuth of the temporal point.
+    /// Returns the temporal azimuth of the temporal point, in radians clockwise from the north.
     ///
     /// ## Returns
     ///
-    /// A `TFloatSequenceSet` indicating the temporal azimuth of the temporal point.
+    /// A `TFloat` indicating the temporal azimuth of the temporal point, or `None` if the
+    /// point never moves.
     ///
     /// ## MEOS Functions
     ///
@@ -376,6 +386,31 @@ pub trait TPointTrait<const IS_GEODETIC: bool>: Temporal {
         gserialized_to_geometry(gs)
     }
 
+    /// Returns the distinct positions traversed by the temporal point as a geos multipoint,
+    /// regardless of its interpolation.
+    ///
+    /// ## Returns
+    ///
+    /// A `MultiPoint` with the SRID of the temporal point.
+    ///
+    /// ## MEOS Functions
+    ///
+    /// tpoint_values
+    fn traversed(&self) -> Result<Geometry, geos::Error> {
+        let mut count = 0;
+        let values = unsafe {
+            let values = meos_sys::tpoint_values(self.inner(), ptr::addr_of_mut!(count));
+            Vec::from_raw_parts(values, count as usize, count as usize)
+        };
+        let values = values
+            .into_iter()
+            .map(gserialized_to_geometry)
+            .collect::<Result<_, _>>()?;
+        let mut points = Geometry::create_multipoint(values)?;
+        points.set_srid(self.srid() as usize);
+        Ok(points)
+    }
+
     // ------------------------- Spatial Reference System ----------------------
 
     /// Returns the SRID.
@@ -519,6 +554,22 @@ pub trait TPointTrait<const IS_GEODETIC: bool>: Temporal {
         factory::<Self::Enum>(unsafe { meos_sys::temporal_at_values(self.inner(), geoset) })
     }
 
+    /// Returns a new temporal object with the values of `self` restricted to `stbox`.
+    ///
+    /// Args:
+    ///     stbox: The spatiotemporal box to restrict the values of `self` to, borders included.
+    ///
+    /// Returns:
+    ///     A new `TPoint` with the values of `self` inside `stbox`, or `None` if `self` is never
+    ///     inside it.
+    ///
+    /// MEOS Functions:
+    ///     tpoint_at_stbox
+    fn at_stbox(&self, stbox: &STBox) -> Option<Self::Enum> {
+        let result = unsafe { meos_sys::tpoint_at_stbox(self.inner(), stbox.inner(), true) };
+        (!result.is_null()).then(|| factory::<Self::Enum>(result))
+    }
+
     /// Returns a new temporal object with the values of `self` restricted to the complement of `other`.
     ///
     /// Args:
@@ -723,19 +774,19 @@ pub trait TPointTrait<const IS_GEODETIC: bool>: Temporal {
         })
     }
 
-    /// Returns a new temporal boolean indicating whether the temporal point intersects `geometry`.
+    /// Returns a new temporal boolean indicating whether the temporal point is disjoint to `geometry`.
     ///
     /// # Arguments
     ///
-    /// * `geometry` - An object to check for intersection with.
+    /// * `geometry` - An object to check for disjointness with.
     ///
     /// # Returns
     ///
-    /// A new `TBool` indicating whether the temporal point intersects `geometry`.
+    /// A new `TBool` indicating whether the temporal point is disjoint to `geometry`.
     ///
     /// # MEOS Functions
     ///
-    /// * `tintersects_tpoint_geo`
+    /// * `tdisjoint_tpoint_geo`
     fn is_disjoint_to_geometry(&self, geometry: &Geometry) -> Self::TBoolType {
         let geo = geometry_to_gserialized(geometry);
         Self::TBoolType::from_inner_as_temporal(unsafe {
@@ -763,6 +814,37 @@ pub trait TPointTrait<const IS_GEODETIC: bool>: Temporal {
         })
     }
 
+    /// Returns a new temporal point restricted to the times when it is within `distance` of `other`.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The moving object defining the buffer.
+    /// * `distance` - The distance to check in units of the spatial reference system.
+    ///
+    /// # Returns
+    ///
+    /// The portions of the temporal point within `distance` of `other`, or `None` if they never are.
+    ///
+    /// # MEOS Functions
+    ///
+    /// * `tdwithin_tpoint_tpoint`, `tbool_when_true`, `temporal_at_tstzspanset`
+    fn at_dwithin(&self, other: &Self::Enum, distance: f64) -> Option<Self> {
+        let within = unsafe {
+            meos_sys::tdwithin_tpoint_tpoint(self.inner(), other.inner(), distance, true, true)
+        };
+        if within.is_null() {
+            return None;
+        }
+        let periods = unsafe { meos_sys::tbool_when_true(within) };
+        unsafe { libc::free(within as *mut c_void) };
+        if periods.is_null() {
+            return None;
+        }
+        let result = unsafe { meos_sys::temporal_at_tstzspanset(self.inner(), periods) };
+        unsafe { libc::free(periods as *mut c_void) };
+        (!result.is_null()).then(|| Self::from_inner_as_temporal(result))
+    }
+
     /// Returns a new temporal boolean indicating whether the temporal point is within `distance` of `geometry`.
     ///
     /// # Arguments
@@ -804,6 +886,26 @@ pub trait TPointTrait<const IS_GEODETIC: bool>: Temporal {
         })
     }
 
+    /// Returns a new temporal boolean indicating whether the temporal point intersects `other`,
+    /// that is, when both are at the same position.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The temporal point to check for intersection with.
+    ///
+    /// # Returns
+    ///
+    /// A new `TBool` indicating whether the temporal point intersects `other`.
+    ///
+    /// # MEOS Functions
+    ///
+    /// * `tintersects_tpoint_tpoint`
+    fn intersects(&self, other: &Self::Enum) -> Self::TBoolType {
+        Self::TBoolType::from_inner_as_temporal(unsafe {
+            meos_sys::tintersects_tpoint_tpoint(self.inner(), other.inner(), false, false)
+        })
+    }
+
     /// Returns a new temporal boolean indicating whether the temporal point touches `other`.
     ///
     /// # Arguments
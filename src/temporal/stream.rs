@@ -0,0 +1,225 @@
+use std::{
+    ffi::c_void,
+    io::{self, Read, Write},
+    ptr,
+};
+
+use crate::{temporal::temporal::Temporal, MeosEnum, WKBVariant};
+
+/// Writes temporal objects to a byte sink as a stream of length-prefixed WKB records.
+///
+/// Each record is the WKB size as a little-endian `u64` followed by the WKB bytes, so
+/// objects can be written one by one without collecting them in memory first.
+/// Use a [`TemporalReader`] to read them back.
+pub struct TemporalWriter<W: Write> {
+    writer: W,
+    variant: WKBVariant,
+}
+
+impl<W: Write> TemporalWriter<W> {
+    /// Creates a new `TemporalWriter` using the extended WKB variant, which keeps the SRID of points.
+    ///
+    /// ## Arguments
+    /// * `writer` - The byte sink to write to.
+    pub fn new(writer: W) -> Self {
+        Self::with_variant(writer, WKBVariant::Extended)
+    }
+
+    /// Creates a new `TemporalWriter` using the given WKB variant.
+    ///
+    /// ## Arguments
+    /// * `writer` - The byte sink to write to.
+    /// * `variant` - The WKB variant used to encode every object.
+    pub fn with_variant(writer: W, variant: WKBVariant) -> Self {
+        Self { writer, variant }
+    }
+
+    /// Writes `temporal` as a single length-prefixed WKB record.
+    ///
+    /// MEOS Functions:
+    ///     `temporal_as_wkb`
+    pub fn write(&mut self, temporal: &impl Temporal) -> io::Result<()> {
+        let mut size: usize = 0;
+        let wkb = unsafe {
+            meos_sys::temporal_as_wkb(
+                temporal.inner(),
+                self.variant.into(),
+                ptr::addr_of_mut!(size),
+            )
+        };
+        let bytes = unsafe { std::slice::from_raw_parts(wkb, size) };
+        let result = self
+            .writer
+            .write_all(&(size as u64).to_le_bytes())
+            .and_then(|_| self.writer.write_all(bytes));
+        unsafe { libc::free(wkb as *mut c_void) };
+        result
+    }
+
+    /// Flushes the underlying writer.
+    pub fn flush(&mut self) -> io::Result<()> {
+        self.writer.flush()
+    }
+
+    /// Returns the underlying writer.
+    pub fn into_inner(self) -> W {
+        self.writer
+    }
+}
+
+/// Reads temporal objects written by a [`TemporalWriter`].
+pub struct TemporalReader<R: Read> {
+    reader: R,
+    variant: WKBVariant,
+    buffer: Vec<u8>,
+}
+
+impl<R: Read> TemporalReader<R> {
+    /// Creates a new `TemporalReader` for records written with the default variant of
+    /// [`TemporalWriter::new`].
+    ///
+    /// ## Arguments
+    /// * `reader` - The byte source to read from.
+    pub fn new(reader: R) -> Self {
+        Self::with_variant(reader, WKBVariant::Extended)
+    }
+
+    /// Creates a new `TemporalReader` for records written with the given WKB variant.
+    ///
+    /// ## Arguments
+    /// * `reader` - The byte source to read from.
+    /// * `variant` - The WKB variant every object was encoded with.
+    pub fn with_variant(reader: R, variant: WKBVariant) -> Self {
+        Self {
+            reader,
+            variant,
+            buffer: Vec::new(),
+        }
+    }
+
+    /// Reads the next record of the stream.
+    ///
+    /// The record is read as its bytes arrive, so a corrupted size can't allocate more memory
+    /// than the stream actually holds.
+    ///
+    /// ## Returns
+    /// The next temporal object, or `None` once the end of the stream is reached. A stream
+    /// ending within a record returns an `UnexpectedEof` error, and a record that isn't a
+    /// valid temporal object an `InvalidData` error.
+    ///
+    /// MEOS Functions:
+    ///     `temporal_from_wkb`
+    pub fn read<T: MeosEnum>(&mut self) -> io::Result<Option<T>> {
+        let mut prefix = [0; 8];
+        let mut filled = 0;
+        while filled < prefix.len() {
+            match self.reader.read(&mut prefix[filled..]) {
+                Ok(0) if filled == 0 => return Ok(None),
+                Ok(0) => {
+                    return Err(io::Error::new(
+                        io::ErrorKind::UnexpectedEof,
+                        "Stream ended within a record size",
+                    ))
+                }
+                Ok(read) => filled += read,
+                Err(error) if error.kind() == io::ErrorKind::Interrupted => {}
+                Err(error) => return Err(error),
+            }
+        }
+        let size = u64::from_le_bytes(prefix);
+        self.buffer.clear();
+        (&mut self.reader)
+            .take(size)
+            .read_to_end(&mut self.buffer)?;
+        if (self.buffer.len() as u64) < size {
+            return Err(io::Error::new(
+                io::ErrorKind::UnexpectedEof,
+                "Stream ended within a record",
+            ));
+        }
+        T::try_from_wkb(&self.buffer, self.variant)
+            .map(Some)
+            .map_err(|error| io::Error::new(io::ErrorKind::InvalidData, error))
+    }
+
+    /// Returns the underlying reader.
+    pub fn into_inner(self) -> R {
+        self.reader
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{TimeDelta, TimeZone, Utc};
+
+    use super::*;
+    use crate::{
+        meos_initialize,
+        temporal::number::tfloat::{TFloat, TFloatInstant, TFloatSequence},
+    };
+
+    #[test]
+    fn write_and_read_back() {
+        meos_initialize("UTC");
+        let start = Utc.with_ymd_and_hms(2020, 1, 1, 0, 0, 0).unwrap();
+        let sequences: Vec<TFloat> = (0..100)
+            .map(|i| {
+                let sequence: TFloatSequence = (0..100)
+                    .map(|j| {
+                        TFloatInstant::from((
+                            (i * j) as f64,
+                            start + TimeDelta::minutes(i * 100 + j),
+                        ))
+                    })
+                    .collect();
+                TFloat::Sequence(sequence)
+            })
+            .collect();
+
+        let mut writer = TemporalWriter::new(Vec::new());
+        for sequence in &sequences {
+            writer.write(sequence).unwrap();
+        }
+        writer.flush().unwrap();
+        let bytes = writer.into_inner();
+
+        let mut reader = TemporalReader::new(bytes.as_slice());
+        let mut read = Vec::new();
+        while let Some(temporal) = reader.read::<TFloat>().unwrap() {
+            read.push(temporal);
+        }
+        assert_eq!(read.len(), sequences.len());
+        assert!(read.iter().zip(&sequences).all(|(a, b)| a == b));
+    }
+
+    #[test]
+    fn read_truncated_and_invalid_records() {
+        meos_initialize("UTC");
+        let tfloat: TFloat = "[1@2020-01-01, 2@2020-01-02]".parse().unwrap();
+        let mut writer = TemporalWriter::new(Vec::new());
+        writer.write(&tfloat).unwrap();
+        let bytes = writer.into_inner();
+
+        let mut reader = TemporalReader::new(&bytes[..4]);
+        let error = reader.read::<TFloat>().unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::UnexpectedEof);
+
+        let mut reader = TemporalReader::new(&bytes[..bytes.len() - 1]);
+        let error = reader.read::<TFloat>().unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::UnexpectedEof);
+
+        // A huge size must not be allocated up front
+        let mut corrupted = u64::MAX.to_le_bytes().to_vec();
+        corrupted.extend_from_slice(&bytes[8..]);
+        let mut reader = TemporalReader::new(corrupted.as_slice());
+        let error = reader.read::<TFloat>().unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::UnexpectedEof);
+
+        let mut garbage = 4u64.to_le_bytes().to_vec();
+        garbage.extend_from_slice(&[1, 2, 3, 4]);
+        let mut reader = TemporalReader::new(garbage.as_slice());
+        let error = reader.read::<TFloat>().unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::InvalidData);
+        assert!(reader.read::<TFloat>().unwrap().is_none());
+    }
+}
@@ -18,18 +18,18 @@ use crate::{
         },
         datetime::{tstz_span::TsTzSpan, tstz_span_set::TsTzSpanSet},
     },
-    errors::ParseError,
-    factory, impl_from_str,
+    errors::{ParseError, TemporalParseError},
+    factory, impl_from_str, impl_serde, parse_mfjson,
     temporal::{
         temporal::{
-            impl_always_and_ever_value_equality_functions, impl_simple_traits_for_temporal,
-            Temporal,
+            aggregate, impl_always_and_ever_value_equality_functions, impl_ordering_for_temporal,
+            impl_simple_traits_for_temporal, Temporal,
         },
         tinstant::TInstant,
         tsequence::TSequence,
         tsequence_set::TSequenceSet,
     },
-    utils::to_meos_timestamp,
+    utils::{interval_to_duration, to_meos_timestamp},
     MeosEnum,
 };
 
@@ -62,6 +62,7 @@ macro_rules! impl_tbool_traits {
                 }
             }
             impl_simple_traits_for_temporal!($type, with_drop);
+            impl_ordering_for_temporal!($type);
             impl_debug!($type);
 
             impl Temporal for $type {
@@ -199,6 +200,51 @@ pub enum TBool {
 }
 
 impl_from_str!(TBool);
+impl_serde!(TBool, |temporal| unsafe {
+    meos_sys::tbool_out(temporal.inner())
+});
+
+impl TBool {
+    /// Computes the temporal AND of all the given temporal booleans.
+    ///
+    /// At each instant the result is `true` only if every item defined at that instant is `true`.
+    ///
+    /// ## Arguments
+    /// * `items` - A slice of temporal booleans.
+    ///
+    /// ## Returns
+    /// The aggregated `TBool`, or `None` if `items` is empty.
+    ///
+    /// MEOS Functions:
+    ///     `tbool_tand_transfn`, `temporal_tagg_finalfn`
+    pub fn aggregate_and(items: &[TBool]) -> Option<TBool> {
+        aggregate(
+            items,
+            meos_sys::tbool_tand_transfn,
+            meos_sys::temporal_tagg_finalfn,
+        )
+    }
+
+    /// Computes the temporal OR of all the given temporal booleans.
+    ///
+    /// At each instant the result is `true` if any item defined at that instant is `true`.
+    ///
+    /// ## Arguments
+    /// * `items` - A slice of temporal booleans.
+    ///
+    /// ## Returns
+    /// The aggregated `TBool`, or `None` if `items` is empty.
+    ///
+    /// MEOS Functions:
+    ///     `tbool_tor_transfn`, `temporal_tagg_finalfn`
+    pub fn aggregate_or(items: &[TBool]) -> Option<TBool> {
+        aggregate(
+            items,
+            meos_sys::tbool_tor_transfn,
+            meos_sys::temporal_tagg_finalfn,
+        )
+    }
+}
 
 impl MeosEnum for TBool {
     fn from_instant(inner: *mut meos_sys::TInstant) -> Self {
@@ -213,9 +259,8 @@ impl MeosEnum for TBool {
         Self::SequenceSet(TBoolSequenceSet::from_inner(inner))
     }
 
-    fn from_mfjson(mfjson: &str) -> Self {
-        let cstr = CString::new(mfjson).unwrap();
-        factory::<Self>(unsafe { meos_sys::tbool_from_mfjson(cstr.as_ptr()) })
+    fn from_mfjson(mfjson: &str) -> Result<Self, TemporalParseError> {
+        parse_mfjson(mfjson, meos_sys::tbool_from_mfjson)
     }
 }
 
@@ -252,6 +297,40 @@ pub trait TBoolTrait:
     fn at_false(&self) -> Option<Self::Enum> {
         self.at_value(&false)
     }
+
+    /// Returns the fraction of the time during which `self` is defined that it is `true`.
+    ///
+    /// Gaps are not counted. When `self` has no duration, as instants and discrete sequences,
+    /// the fraction of its instants that are `true` is returned instead.
+    ///
+    /// ## Returns
+    /// A value between `0.0` and `1.0`.
+    ///
+    /// MEOS Functions:
+    ///     `tbool_when_true`, `tstzspanset_duration`, `temporal_duration`
+    fn true_fraction(&self) -> f64 {
+        let total = self.duration(false).num_microseconds().unwrap_or(i64::MAX);
+        if total == 0 {
+            let timestamps = self.timestamps();
+            let true_count = timestamps
+                .iter()
+                .filter(|&&timestamp| self.value_at_timestamp(timestamp) == Some(true))
+                .count();
+            return true_count as f64 / timestamps.len() as f64;
+        }
+        let when_true = unsafe { meos_sys::tbool_when_true(self.inner()) };
+        if when_true.is_null() {
+            return 0.0;
+        }
+        let true_duration = unsafe {
+            let interval = meos_sys::tstzspanset_duration(when_true, false);
+            let duration = interval_to_duration(interval.read());
+            libc::free(interval as *mut c_void);
+            libc::free(when_true as *mut c_void);
+            duration
+        };
+        true_duration.num_microseconds().unwrap_or(i64::MAX) as f64 / total as f64
+    }
 }
 
 pub struct TBoolInstant {
@@ -419,6 +498,7 @@ impl Collection for TBool {
     }
 }
 impl_simple_traits_for_temporal!(TBool);
+impl_ordering_for_temporal!(TBool);
 
 impl Temporal for TBool {
     type TI = TBoolInstant;
@@ -547,6 +627,7 @@ mod tests {
     use crate::meos_initialize;
 
     use super::*;
+    use chrono::Utc;
 
     #[test]
     fn instant_tbool() {
@@ -580,4 +661,36 @@ mod tests {
             format!("SequenceSet({})", string.to_owned())
         );
     }
+
+    #[test]
+    fn aggregate_or_tbool() {
+        meos_initialize("UTC");
+        let windows: Vec<TBool> = [
+            "[t@2018-01-01 08:00:00+00, f@2018-01-01 09:00:00+00]",
+            "[f@2018-01-01 08:00:00+00, t@2018-01-01 10:00:00+00, t@2018-01-01 11:00:00+00]",
+            "[f@2018-01-01 10:30:00+00, t@2018-01-01 12:00:00+00]",
+        ]
+        .iter()
+        .map(|window| window.parse().unwrap())
+        .collect();
+
+        let any_active = TBool::aggregate_or(&windows).unwrap();
+        let at = |h, m| Utc.with_ymd_and_hms(2018, 1, 1, h, m, 0).unwrap();
+        assert_eq!(any_active.value_at_timestamp(at(8, 30)), Some(true));
+        assert_eq!(any_active.value_at_timestamp(at(9, 30)), Some(false));
+        assert_eq!(any_active.value_at_timestamp(at(10, 45)), Some(true));
+        assert_eq!(any_active.value_at_timestamp(at(11, 30)), Some(false));
+        assert_eq!(any_active.value_at_timestamp(at(12, 0)), Some(true));
+        assert!(TBool::aggregate_or(&[]).is_none());
+    }
+
+    #[test]
+    fn true_fraction_tbool() {
+        meos_initialize("UTC");
+        let tbool = TBool::from_str(
+            "[t@2018-01-01 08:00:00+00, f@2018-01-01 08:30:00+00, t@2018-01-01 09:00:00+00]",
+        )
+        .unwrap();
+        assert!((tbool.true_fraction() - 0.5).abs() < 1e-9);
+    }
 }
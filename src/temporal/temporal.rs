@@ -1,25 +1,73 @@
 use std::{
-    ffi::{CStr, CString},
+    ffi::{c_void, CStr, CString},
+    fmt::Debug,
     hash::Hash,
     ptr,
 };
 
 use crate::{
+    capture_meos_error,
     collections::{
         base::{collection::Collection, span::Span, span_set::SpanSet},
         datetime::{tstz_span::TsTzSpan, tstz_span_set::TsTzSpanSet},
     },
+    errors::MeosError,
     factory,
-    utils::{create_interval, from_interval, from_meos_timestamp, to_meos_timestamp},
+    utils::{
+        duration_to_interval, format_timestamp, from_meos_timestamp, interval_to_duration,
+        to_meos_timestamp,
+    },
     BoundingBox, MeosEnum,
 };
-use chrono::{DateTime, TimeDelta, TimeZone, Utc};
+use chrono::{DateTime, NaiveTime, TimeDelta, TimeZone, Utc};
 
 use super::{
     interpolation::TInterpolation, tbool::TBoolTrait, tinstant::TInstant, tsequence::TSequence,
     tsequence_set::TSequenceSet,
 };
 
+/// Summary of the time gaps between consecutive timestamps of a temporal object.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SamplingStats {
+    /// Shortest gap between two consecutive timestamps.
+    pub min_gap: TimeDelta,
+    /// Longest gap between two consecutive timestamps.
+    pub max_gap: TimeDelta,
+    /// Average gap between two consecutive timestamps.
+    pub mean_gap: TimeDelta,
+    /// Number of timestamps.
+    pub count: usize,
+}
+
+/// How `Temporal::fill_gaps` bridges the gaps between sequences.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FillMethod {
+    /// Keeps the last value before the gap until the next sequence starts.
+    Hold,
+    /// Interpolates linearly between the values around the gap.
+    Linear,
+}
+
+/// Folds `items` with the MEOS aggregate `transition` function and returns the result of
+/// `finalize`, or `None` if `items` is empty.
+pub(crate) fn aggregate<T: MeosEnum>(
+    items: &[impl Temporal],
+    transition: unsafe extern "C" fn(
+        *mut meos_sys::SkipList,
+        *const meos_sys::Temporal,
+    ) -> *mut meos_sys::SkipList,
+    finalize: unsafe extern "C" fn(*mut meos_sys::SkipList) -> *mut meos_sys::Temporal,
+) -> Option<T> {
+    if items.is_empty() {
+        return None;
+    }
+    let state = items.iter().fold(ptr::null_mut(), |state, item| unsafe {
+        transition(state, item.inner())
+    });
+    let result = unsafe { finalize(state) };
+    (!result.is_null()).then(|| factory::<T>(result))
+}
+
 pub trait Temporal: Collection + Hash {
     type TI: TInstant;
     type TS: TSequence;
@@ -47,6 +95,36 @@ pub trait Temporal: Collection + Hash {
         string.to_str().unwrap().parse().unwrap()
     }
 
+    /// Returns whether the temporal object has linear interpolation.
+    fn is_linear(&self) -> bool {
+        self.interpolation() == TInterpolation::Linear
+    }
+
+    /// Returns whether the temporal object has stepwise interpolation.
+    fn is_stepwise(&self) -> bool {
+        self.interpolation() == TInterpolation::Stepwise
+    }
+
+    /// Returns whether the temporal object has discrete interpolation.
+    ///
+    /// Instants have no interpolation, so all three predicates are `false` for them.
+    fn is_discrete(&self) -> bool {
+        self.interpolation() == TInterpolation::Discrete
+    }
+
+    /// Returns the name of the temporal type, such as `"tint"`, `"tfloat"` or `"tgeompoint"`.
+    ///
+    /// ## Returns
+    /// The MEOS name of the type of the temporal object.
+    ///
+    /// MEOS Functions:
+    ///     `meostype_name`
+    fn type_name(&self) -> &'static str {
+        let temptype = unsafe { (*self.inner()).temptype } as meos_sys::meosType;
+        let name = unsafe { CStr::from_ptr(meos_sys::meostype_name(temptype)) };
+        name.to_str().unwrap()
+    }
+
     /// Returns the set of unique values in the temporal object.
     ///
     /// ## Returns
@@ -104,7 +182,110 @@ pub trait Temporal: Collection + Hash {
     /// ## Returns
     /// The duration of the temporal object.
     fn duration(&self, ignore_gaps: bool) -> TimeDelta {
-        from_interval(unsafe { meos_sys::temporal_duration(self.inner(), ignore_gaps).read() })
+        interval_to_duration(unsafe {
+            meos_sys::temporal_duration(self.inner(), ignore_gaps).read()
+        })
+    }
+
+    /// Returns the fraction of `window` during which the temporal object is defined.
+    ///
+    /// ## Arguments
+    /// * `window` - The time window to measure the coverage of.
+    ///
+    /// ## Returns
+    /// A value between `0.0` and `1.0`. Instantaneous windows have a coverage of `0.0`.
+    ///
+    /// MEOS Functions:
+    ///     `temporal_time`, `intersection_spanset_span`, `tstzspanset_duration`
+    fn coverage_fraction(&self, window: &TsTzSpan) -> f64 {
+        let window_duration = window.duration().num_microseconds().unwrap_or(i64::MAX);
+        if window_duration == 0 {
+            return 0.0;
+        }
+        let time = self.time();
+        let covered = unsafe { meos_sys::intersection_spanset_span(time.inner(), window.inner()) };
+        if covered.is_null() {
+            return 0.0;
+        }
+        let covered_duration = unsafe {
+            let interval = meos_sys::tstzspanset_duration(covered, false);
+            let duration = interval_to_duration(interval.read());
+            libc::free(interval as *mut c_void);
+            libc::free(covered as *mut c_void);
+            duration
+        };
+        covered_duration.num_microseconds().unwrap_or(i64::MAX) as f64 / window_duration as f64
+    }
+
+    /// Returns the duration of the time during which both `self` and `other` are defined.
+    ///
+    /// ## Arguments
+    /// * `other` - The temporal object to compare with.
+    ///
+    /// ## Returns
+    /// The duration of the intersection of their time domains, zero if they never coexist.
+    ///
+    /// MEOS Functions:
+    ///     `temporal_time`, `intersection_spanset_spanset`, `tstzspanset_duration`
+    fn overlap_duration(&self, other: &Self) -> TimeDelta {
+        let (time, other_time) = (self.time(), other.time());
+        let overlap =
+            unsafe { meos_sys::intersection_spanset_spanset(time.inner(), other_time.inner()) };
+        if overlap.is_null() {
+            return TimeDelta::zero();
+        }
+        unsafe {
+            let interval = meos_sys::tstzspanset_duration(overlap, false);
+            let duration = interval_to_duration(interval.read());
+            libc::free(interval as *mut c_void);
+            libc::free(overlap as *mut c_void);
+            duration
+        }
+    }
+
+    /// Returns the `Debug` representation of the temporal object with its timestamps in the
+    /// timezone `tz`, instead of the one MEOS was initialized with.
+    ///
+    /// The values are printed by MEOS and the timestamp after each `@` is replaced by the one
+    /// of the matching instant, formatted in `tz`.
+    ///
+    /// ## Arguments
+    /// * `tz` - The timezone, e.g. `Utc` or a `chrono_tz::Tz`.
+    ///
+    /// MEOS Functions:
+    ///     `temporal_instants`
+    fn to_string_in_tz<Tz: TimeZone>(&self, tz: &Tz) -> String
+    where
+        Self: Debug,
+    {
+        let text = format!("{self:?}");
+        let mut timestamps = self
+            .instants()
+            .into_iter()
+            .map(|instant| format_timestamp(&instant.timestamp(), tz));
+        let mut result = String::with_capacity(text.len());
+        let (mut quoted, mut escaped, mut in_timestamp) = (false, false, false);
+        for c in text.chars() {
+            if in_timestamp {
+                if !matches!(c, ',' | ']' | ')' | '}') {
+                    continue;
+                }
+                in_timestamp = false;
+            } else if quoted {
+                // Text values are quoted and may contain `@`
+                quoted = escaped || c != '"';
+                escaped = !escaped && c == '\\';
+            } else if c == '"' {
+                quoted = true;
+            } else if c == '@' {
+                result.push(c);
+                result.push_str(&timestamps.next().unwrap_or_default());
+                in_timestamp = true;
+                continue;
+            }
+            result.push(c);
+        }
+        result
     }
 
     /// Returns the number of instants in the temporal object.
@@ -167,17 +348,90 @@ pub trait Temporal: Collection + Hash {
 
     /// Returns the list of instants in the temporal object.
     ///
+    /// `temporal_instants` returns pointers into `self`, so every instant is copied and only
+    /// the array is freed.
+    ///
     /// ## Returns
     /// A list of instants.
+    ///
+    /// MEOS Functions:
+    ///     `temporal_instants`, `temporal_copy`
     fn instants(&self) -> Vec<Self::TI> {
         let mut count = 0;
         unsafe {
             let instants = meos_sys::temporal_instants(self.inner(), ptr::addr_of_mut!(count));
+            let copies = std::slice::from_raw_parts(instants, count as usize)
+                .iter()
+                .map(|&instant| {
+                    <Self::TI as TInstant>::from_inner(meos_sys::temporal_copy(
+                        instant as *const meos_sys::Temporal,
+                    )
+                        as *mut meos_sys::TInstant)
+                })
+                .collect();
+            libc::free(instants as *mut c_void);
+            copies
+        }
+    }
 
-            Vec::from_raw_parts(instants, count as usize, count as usize)
+    /// Returns the instant closest in time to `timestamp`, which may lie outside the time
+    /// domain of the temporal object. Ties are resolved in favour of the earliest instant.
+    ///
+    /// ## Arguments
+    /// * `timestamp` - The timestamp to look up.
+    ///
+    /// ## Returns
+    /// The nearest instant, or `None` if the temporal object has no instants.
+    ///
+    /// MEOS Functions:
+    ///     `temporal_instants`, `temporal_copy`
+    fn nearest_instant(&self, timestamp: DateTime<Utc>) -> Option<Self::TI> {
+        let target = to_meos_timestamp(&timestamp);
+        let mut count = 0;
+        unsafe {
+            let instants = meos_sys::temporal_instants(self.inner(), ptr::addr_of_mut!(count));
+            let nearest = std::slice::from_raw_parts(instants, count as usize)
                 .iter()
-                .map(|&instant| <Self::TI as TInstant>::from_inner(instant))
-                .collect()
+                .min_by_key(|&&instant| ((*instant).t - target).unsigned_abs())
+                .map(|&instant| {
+                    meos_sys::temporal_copy(instant as *const meos_sys::Temporal)
+                        as *mut meos_sys::TInstant
+                });
+            libc::free(instants as *mut c_void);
+            nearest.map(<Self::TI as TInstant>::from_inner)
+        }
+    }
+
+    /// Returns the first instant, in time order, whose value satisfies `predicate`.
+    ///
+    /// Only the stored instants are inspected, not the values interpolated between them.
+    ///
+    /// ## Arguments
+    /// * `predicate` - The condition the value of the instant must satisfy.
+    ///
+    /// ## Returns
+    /// The first matching instant, or `None` if no instant matches.
+    ///
+    /// MEOS Functions:
+    ///     `temporal_instants`, `temporal_copy`
+    fn find_instant<F: Fn(&Self::Type) -> bool>(&self, predicate: F) -> Option<Self::TI>
+    where
+        Self::TI: Collection<Type = Self::Type>,
+    {
+        let mut count = 0;
+        unsafe {
+            let instants = meos_sys::temporal_instants(self.inner(), ptr::addr_of_mut!(count));
+            let found = std::slice::from_raw_parts(instants, count as usize)
+                .iter()
+                .map(|&instant| {
+                    <Self::TI as TInstant>::from_inner(meos_sys::temporal_copy(
+                        instant as *const meos_sys::Temporal,
+                    )
+                        as *mut meos_sys::TInstant)
+                })
+                .find(|instant| predicate(&instant.value()));
+            libc::free(instants as *mut c_void);
+            found
         }
     }
 
@@ -241,6 +495,33 @@ pub trait Temporal: Collection + Hash {
         }
     }
 
+    /// Returns statistics about the gaps between consecutive timestamps, useful to detect
+    /// irregular sampling.
+    ///
+    /// ## Returns
+    /// A `SamplingStats`. All gaps are zero when there are less than two timestamps.
+    ///
+    /// MEOS Functions:
+    ///     `temporal_timestamps`
+    fn sampling_stats(&self) -> SamplingStats {
+        let timestamps = self.timestamps();
+        let gaps: Vec<TimeDelta> = timestamps
+            .windows(2)
+            .map(|pair| pair[1] - pair[0])
+            .collect();
+        let total = gaps.iter().fold(TimeDelta::zero(), |acc, &gap| acc + gap);
+        SamplingStats {
+            min_gap: gaps.iter().min().copied().unwrap_or_default(),
+            max_gap: gaps.iter().max().copied().unwrap_or_default(),
+            mean_gap: if gaps.is_empty() {
+                TimeDelta::zero()
+            } else {
+                total / gaps.len() as i32
+            },
+            count: timestamps.len(),
+        }
+    }
+
     /// Returns the list of segments in the temporal object.
     ///
     /// ## Returns
@@ -260,6 +541,160 @@ pub trait Temporal: Collection + Hash {
         }
     }
 
+    /// Returns the time span of every piece of the temporal object with its value, e.g. to plot
+    /// it.
+    ///
+    /// For stepwise interpolation, every maximal period with a constant value is returned
+    /// once. For linear interpolation, every segment is returned with its start value. For
+    /// discrete interpolation, every instant is returned with an instantaneous span.
+    ///
+    /// ## Returns
+    /// A vector of `(span, value)` pairs in time order.
+    ///
+    /// MEOS Functions:
+    ///    `temporal_segments`
+    fn intervals(&self) -> Vec<(TsTzSpan, Self::Type)>
+    where
+        Self::TS: Collection<Type = Self::Type>,
+        Self::Type: PartialEq,
+    {
+        let stepwise = self.interpolation() == TInterpolation::Stepwise;
+        let mut intervals: Vec<(TsTzSpan, Self::Type)> = Vec::new();
+        for segment in self.segments() {
+            let (span, value) = (segment.timespan(), segment.start_value());
+            match intervals.last_mut() {
+                Some((last_span, last_value))
+                    if stepwise
+                        && *last_value == value
+                        && last_span.upper() == span.lower()
+                        && (last_span.is_upper_inclusive() || span.is_lower_inclusive()) =>
+                {
+                    *last_span = last_span.with_upper(span.upper(), span.is_upper_inclusive());
+                }
+                _ => intervals.push((span, value)),
+            }
+        }
+        intervals
+    }
+
+    /// Returns the value held for the longest total time, e.g. to summarize a categorical
+    /// signal.
+    ///
+    /// Instantaneous pieces, such as the instants of a discrete sequence, are only used to break
+    /// ties, by counting how often each value appears. Remaining ties are won by the value that
+    /// appears first.
+    ///
+    /// ## Returns
+    /// The time-weighted mode of the temporal object.
+    ///
+    /// MEOS Functions:
+    ///    `temporal_segments`
+    fn mode(&self) -> Option<Self::Type>
+    where
+        Self::TS: Collection<Type = Self::Type>,
+        Self::Type: PartialEq,
+    {
+        if unsafe { (*self.inner()).subtype } as u32 == meos_sys::tempSubtype_TINSTANT {
+            return Some(self.start_value());
+        }
+        let mut totals: Vec<(Self::Type, TimeDelta, usize)> = Vec::new();
+        for (span, value) in self.intervals() {
+            match totals
+                .iter_mut()
+                .find(|(total_value, _, _)| *total_value == value)
+            {
+                Some((_, duration, count)) => {
+                    *duration += span.duration();
+                    *count += 1;
+                }
+                None => totals.push((value, span.duration(), 1)),
+            }
+        }
+        totals
+            .into_iter()
+            .rev()
+            .max_by_key(|&(_, duration, count)| (duration, count))
+            .map(|(value, _, _)| value)
+    }
+
+    /// Returns each maximal gap-free component of the temporal object as its own sequence.
+    ///
+    /// A sequence has a single component, a sequence set has one per sequence, and an
+    /// instant is returned as a discrete sequence.
+    ///
+    /// ## Returns
+    /// A list of sequences in time order.
+    ///
+    /// MEOS Functions:
+    ///    `temporal_sequences`
+    fn defined_sequences(&self) -> Vec<Self::TS> {
+        if unsafe { (*self.inner()).subtype } as u32 == meos_sys::tempSubtype_TINSTANT {
+            return vec![self.to_sequence(TInterpolation::Discrete)];
+        }
+        let mut count = 0;
+        unsafe {
+            let sequences = meos_sys::temporal_sequences(self.inner(), ptr::addr_of_mut!(count));
+            Vec::from_raw_parts(sequences, count as usize, count as usize)
+                .into_iter()
+                .map(<Self::TS as TSequence>::from_inner)
+                .collect()
+        }
+    }
+
+    /// Returns whether `self` and `other` take the same values at the union of their timestamps,
+    /// regardless of their declared interpolation.
+    ///
+    /// Unlike `PartialEq`, a stepwise and a discrete representation of the same instants are
+    /// considered equal.
+    ///
+    /// ## Arguments
+    /// * `other` - The temporal object to compare with.
+    ///
+    /// ## Returns
+    /// `true` if both objects agree on every sampled timestamp, `false` otherwise.
+    fn same_values(&self, other: &Self) -> bool
+    where
+        Self::Type: PartialEq,
+    {
+        let mut timestamps = self.timestamps();
+        timestamps.extend(other.timestamps());
+        timestamps.sort();
+        timestamps.dedup();
+        timestamps.into_iter().all(|timestamp| {
+            self.value_at_timestamp(timestamp) == other.value_at_timestamp(timestamp)
+        })
+    }
+
+    /// Returns the timestamps, among the union of the timestamps of `self` and `other`, at which
+    /// both objects are defined but take different values.
+    ///
+    /// ## Arguments
+    /// * `other` - The temporal object to compare with.
+    ///
+    /// ## Returns
+    /// A list of `(timestamp, value in self, value in other)` tuples, in time order.
+    fn diff(&self, other: &Self) -> Vec<(DateTime<Utc>, Self::Type, Self::Type)>
+    where
+        Self::Type: PartialEq,
+    {
+        let mut timestamps = self.timestamps();
+        timestamps.extend(other.timestamps());
+        timestamps.sort();
+        timestamps.dedup();
+        timestamps
+            .into_iter()
+            .filter_map(|timestamp| {
+                match (
+                    self.value_at_timestamp(timestamp),
+                    other.value_at_timestamp(timestamp),
+                ) {
+                    (Some(left), Some(right)) if left != right => Some((timestamp, left, right)),
+                    _ => None,
+                }
+            })
+            .collect()
+    }
+
     // ------------------------- Transformations -------------------------------
 
     /// Returns a new `Temporal` object with the given interpolation.
@@ -272,6 +707,281 @@ pub trait Temporal: Collection + Hash {
         })
     }
 
+    /// Returns a new `Temporal` object without its redundant instants, i.e., the instants
+    /// that can be removed without changing the value at any timestamp.
+    ///
+    /// For stepwise interpolation these are the instants whose value equals the value of
+    /// the previous one, for linear interpolation the instants that are collinear with their
+    /// neighbours. Instants and discrete sequences are returned unchanged.
+    ///
+    /// ## Returns
+    /// A temporal object of the same subtype with the same values and fewer or equal instants.
+    ///
+    /// MEOS Functions:
+    ///     `temporal_sequences`, `temporal_instants`, `tsequence_make`, `tsequenceset_make`
+    fn compact(&self) -> Self {
+        let interpolation = self.interpolation();
+        if matches!(
+            interpolation,
+            TInterpolation::None | TInterpolation::Discrete
+        ) {
+            return Self::from_inner_as_temporal(unsafe { meos_sys::temporal_copy(self.inner()) });
+        }
+        unsafe {
+            let mut count = 0;
+            let sequences = meos_sys::temporal_sequences(self.inner(), ptr::addr_of_mut!(count));
+            let sequences = Vec::from_raw_parts(sequences, count as usize, count as usize);
+            let mut compacted: Vec<*const meos_sys::TSequence> = sequences
+                .into_iter()
+                .map(|sequence| {
+                    let sequence = sequence as *const meos_sys::Temporal;
+                    let mut num_instants = 0;
+                    let instants =
+                        meos_sys::temporal_instants(sequence, ptr::addr_of_mut!(num_instants));
+                    let result = meos_sys::tsequence_make(
+                        instants as *mut *const meos_sys::TInstant,
+                        num_instants,
+                        meos_sys::temporal_lower_inc(sequence),
+                        meos_sys::temporal_upper_inc(sequence),
+                        interpolation as u32,
+                        true,
+                    );
+                    libc::free(instants as *mut c_void);
+                    libc::free(sequence as *mut c_void);
+                    result as *const _
+                })
+                .collect();
+            if (*self.inner()).subtype as u32 == meos_sys::tempSubtype_TSEQUENCE {
+                Self::from_inner_as_temporal(compacted[0] as *mut meos_sys::Temporal)
+            } else {
+                let result = meos_sys::tsequenceset_make(
+                    compacted.as_mut_ptr(),
+                    compacted.len() as i32,
+                    true,
+                );
+                for sequence in compacted {
+                    libc::free(sequence as *mut c_void);
+                }
+                Self::from_inner_as_temporal(result as *mut meos_sys::Temporal)
+            }
+        }
+    }
+
+    /// Returns a new `Temporal` object with every timestamp rounded to the nearest point of
+    /// the grid `origin + k * step`.
+    ///
+    /// Unlike `temporal_sample` and `temporal_precision`, which bucket the object, the
+    /// instants keep their values and only move in time. Timestamps halfway between two grid
+    /// points are rounded up. When several instants are snapped to the same grid point, only
+    /// the first of them is kept.
+    ///
+    /// ## Arguments
+    /// * `step` - The spacing of the grid, must be positive.
+    /// * `origin` - A point of the grid.
+    ///
+    /// ## Returns
+    /// A temporal object of the same subtype with the snapped instants.
+    ///
+    /// ## Panics
+    /// If `step` is not positive.
+    ///
+    /// MEOS Functions:
+    ///     `temporal_instants`, `temporal_sequences`, `tsequence_make`, `tsequenceset_make`
+    fn snap_time(&self, step: TimeDelta, origin: DateTime<Utc>) -> Self {
+        let step = step
+            .num_microseconds()
+            .filter(|step| *step > 0)
+            .expect("The step must be positive");
+        let origin = to_meos_timestamp(&origin);
+        let snap =
+            |t: meos_sys::TimestampTz| origin + (t - origin + step / 2).div_euclid(step) * step;
+        let interpolation = self.interpolation();
+        let mut last = None;
+        // Copies the instants of `temporal` whose snapped timestamp is after the last one kept
+        let mut snap_instants = |temporal: *const meos_sys::Temporal| unsafe {
+            let mut count = 0;
+            let instants = meos_sys::temporal_instants(temporal, ptr::addr_of_mut!(count));
+            let mut snapped = Vec::new();
+            for &instant in std::slice::from_raw_parts(instants, count as usize) {
+                let t = snap((*instant).t);
+                if last.is_some_and(|last| t <= last) {
+                    continue;
+                }
+                last = Some(t);
+                let copy = meos_sys::temporal_copy(instant as *const meos_sys::Temporal)
+                    as *mut meos_sys::TInstant;
+                (*copy).t = t;
+                snapped.push(copy as *const meos_sys::TInstant);
+            }
+            libc::free(instants as *mut c_void);
+            snapped
+        };
+        let make_sequence = |mut instants: Vec<*const meos_sys::TInstant>, lower_inc, upper_inc| unsafe {
+            let single = instants.len() == 1;
+            let result = meos_sys::tsequence_make(
+                instants.as_mut_ptr(),
+                instants.len() as i32,
+                lower_inc || single,
+                upper_inc || single,
+                interpolation as u32,
+                true,
+            );
+            for instant in instants {
+                libc::free(instant as *mut c_void);
+            }
+            result as *const meos_sys::TSequence
+        };
+        unsafe {
+            match (*self.inner()).subtype as u32 {
+                meos_sys::tempSubtype_TINSTANT => {
+                    let instant = snap_instants(self.inner())[0];
+                    Self::from_inner_as_temporal(instant as *mut meos_sys::Temporal)
+                }
+                meos_sys::tempSubtype_TSEQUENCE => {
+                    let instants = snap_instants(self.inner());
+                    let sequence = make_sequence(
+                        instants,
+                        meos_sys::temporal_lower_inc(self.inner()),
+                        meos_sys::temporal_upper_inc(self.inner()),
+                    );
+                    Self::from_inner_as_temporal(sequence as *mut meos_sys::Temporal)
+                }
+                _ => {
+                    let mut count = 0;
+                    let sequences =
+                        meos_sys::temporal_sequences(self.inner(), ptr::addr_of_mut!(count));
+                    let sequences = Vec::from_raw_parts(sequences, count as usize, count as usize);
+                    let mut snapped: Vec<*const meos_sys::TSequence> = Vec::new();
+                    for sequence in sequences {
+                        let sequence = sequence as *const meos_sys::Temporal;
+                        let instants = snap_instants(sequence);
+                        if !instants.is_empty() {
+                            snapped.push(make_sequence(
+                                instants,
+                                meos_sys::temporal_lower_inc(sequence),
+                                meos_sys::temporal_upper_inc(sequence),
+                            ));
+                        }
+                        libc::free(sequence as *mut c_void);
+                    }
+                    let result = meos_sys::tsequenceset_make(
+                        snapped.as_mut_ptr(),
+                        snapped.len() as i32,
+                        true,
+                    );
+                    for sequence in snapped {
+                        libc::free(sequence as *mut c_void);
+                    }
+                    Self::from_inner_as_temporal(result as *mut meos_sys::Temporal)
+                }
+            }
+        }
+    }
+
+    /// Returns a new `Temporal` object where the gaps between consecutive sequences that are
+    /// not longer than `max_gap` are bridged, joining those sequences into one.
+    ///
+    /// With `FillMethod::Hold` and linear interpolation, an instant with the last value before
+    /// the gap is added one microsecond before the next sequence starts. Stepwise sequences can
+    /// only hold their values, so both methods give the same result for them. Objects that are
+    /// not sequence sets, or whose interpolation is discrete, are returned unchanged.
+    ///
+    /// ## Arguments
+    /// * `max_gap` - The longest gap to bridge.
+    /// * `method` - How to fill the bridged gaps.
+    ///
+    /// ## Returns
+    /// A temporal object of the same subtype with fewer or equal sequences.
+    ///
+    /// MEOS Functions:
+    ///     `temporal_sequences`, `temporal_instants`, `tsequence_make`, `tsequenceset_make`
+    fn fill_gaps(&self, max_gap: TimeDelta, method: FillMethod) -> Self {
+        let interpolation = self.interpolation();
+        if unsafe { (*self.inner()).subtype } as u32 != meos_sys::tempSubtype_TSEQUENCESET
+            || matches!(
+                interpolation,
+                TInterpolation::None | TInterpolation::Discrete
+            )
+        {
+            return Self::from_inner_as_temporal(unsafe { meos_sys::temporal_copy(self.inner()) });
+        }
+        let max_gap = max_gap.num_microseconds().unwrap_or(i64::MAX);
+        unsafe {
+            let mut count = 0;
+            let sequences = meos_sys::temporal_sequences(self.inner(), ptr::addr_of_mut!(count));
+            let sequences = Vec::from_raw_parts(sequences, count as usize, count as usize);
+            // Runs of sequences to join, with the instants added to fill their gaps
+            let mut runs: Vec<Vec<*mut meos_sys::TSequence>> = Vec::new();
+            let mut added: Vec<*mut meos_sys::TInstant> = Vec::new();
+            let mut instants: Vec<Vec<*const meos_sys::TInstant>> = Vec::new();
+            for sequence in sequences {
+                let temporal = sequence as *const meos_sys::Temporal;
+                let mut num_instants = 0;
+                let raw = meos_sys::temporal_instants(temporal, ptr::addr_of_mut!(num_instants));
+                let sequence_instants = std::slice::from_raw_parts(
+                    raw as *const *const meos_sys::TInstant,
+                    num_instants as usize,
+                );
+                let start = (*sequence_instants[0]).t;
+                let previous_end = instants
+                    .last()
+                    .and_then(|previous| previous.last())
+                    .map(|&last| (*last).t);
+                match previous_end {
+                    Some(end) if start > end && start - end <= max_gap => {
+                        let run_instants = instants.last_mut().unwrap();
+                        if method == FillMethod::Hold
+                            && interpolation == TInterpolation::Linear
+                            && start - 1 > end
+                        {
+                            let hold = meos_sys::temporal_copy(
+                                run_instants[run_instants.len() - 1] as *const meos_sys::Temporal,
+                            ) as *mut meos_sys::TInstant;
+                            (*hold).t = start - 1;
+                            added.push(hold);
+                            run_instants.push(hold);
+                        }
+                        run_instants.extend_from_slice(sequence_instants);
+                        runs.last_mut().unwrap().push(sequence);
+                    }
+                    _ => {
+                        instants.push(sequence_instants.to_vec());
+                        runs.push(vec![sequence]);
+                    }
+                }
+                libc::free(raw as *mut c_void);
+            }
+            let mut filled: Vec<*const meos_sys::TSequence> = runs
+                .iter()
+                .zip(instants.iter_mut())
+                .map(|(run, run_instants)| {
+                    let first = run[0] as *const meos_sys::Temporal;
+                    let last = run[run.len() - 1] as *const meos_sys::Temporal;
+                    meos_sys::tsequence_make(
+                        run_instants.as_mut_ptr(),
+                        run_instants.len() as i32,
+                        meos_sys::temporal_lower_inc(first),
+                        meos_sys::temporal_upper_inc(last),
+                        interpolation as u32,
+                        true,
+                    ) as *const _
+                })
+                .collect();
+            let result =
+                meos_sys::tsequenceset_make(filled.as_mut_ptr(), filled.len() as i32, true);
+            for sequence in filled {
+                libc::free(sequence as *mut c_void);
+            }
+            for sequence in runs.into_iter().flatten() {
+                libc::free(sequence as *mut c_void);
+            }
+            for instant in added {
+                libc::free(instant as *mut c_void);
+            }
+            Self::from_inner_as_temporal(result as *mut meos_sys::Temporal)
+        }
+    }
+
     /// Returns a new `Temporal` with the temporal dimension shifted by `delta`.
     ///
     /// ## Arguments
@@ -283,6 +993,17 @@ pub trait Temporal: Collection + Hash {
         self.shift_scale_time(Some(delta), None)
     }
 
+    /// Returns a new `Temporal` shifted so that it starts at `to`.
+    ///
+    /// ## Arguments
+    /// * `to` - The new start timestamp.
+    ///
+    /// MEOS Functions:
+    ///     `temporal_shift_time`
+    fn align_start(&self, to: DateTime<Utc>) -> Self {
+        self.shift_time(to - self.start_timestamp())
+    }
+
     /// Returns a new `Temporal` scaled so the temporal dimension has duration `duration`.
     ///
     /// ## Arguments
@@ -305,7 +1026,7 @@ pub trait Temporal: Collection + Hash {
     fn shift_scale_time(&self, shift: Option<TimeDelta>, duration: Option<TimeDelta>) -> Self {
         let d = {
             if let Some(d) = shift {
-                &*Box::new(create_interval(d)) as *const meos_sys::Interval
+                &*Box::new(duration_to_interval(d)) as *const meos_sys::Interval
             } else {
                 std::ptr::null()
             }
@@ -313,7 +1034,7 @@ pub trait Temporal: Collection + Hash {
 
         let w = {
             if let Some(w) = duration {
-                &*Box::new(create_interval(w)) as *const meos_sys::Interval
+                &*Box::new(duration_to_interval(w)) as *const meos_sys::Interval
             } else {
                 std::ptr::null()
             }
@@ -323,6 +1044,50 @@ pub trait Temporal: Collection + Hash {
         Self::from_inner_as_temporal(modified)
     }
 
+    /// Returns a new temporal object repeating `self` `times` times, each repetition shifted by
+    /// the duration of `self` plus `gap`.
+    ///
+    /// When `gap` is zero, every repetition but the last excludes its end instant, so that it
+    /// does not collide with the start of the next one.
+    ///
+    /// ## Arguments
+    /// * `times` - Number of repetitions.
+    /// * `gap` - Time left between the end of a repetition and the start of the next one.
+    ///
+    /// ## Returns
+    /// A new temporal object, usually a sequence set, or `None` if `times` is zero, `gap` is
+    /// negative, or `self` has no duration, e.g. an instant, and `gap` is zero, as every
+    /// repetition would then fall on the same time.
+    ///
+    /// MEOS Functions:
+    ///     `temporal_shift_time`, `temporal_minus_timestamptz`, `temporal_merge_array`
+    fn repeat(&self, times: usize, gap: TimeDelta) -> Option<Self::Enum> {
+        let step = self.duration(false) + gap;
+        if times == 0 || gap < TimeDelta::zero() || step.is_zero() {
+            return None;
+        }
+        let copies: Vec<Self::Enum> = (0..times)
+            .filter_map(|i| {
+                let interval = duration_to_interval(step * i as i32);
+                let copy =
+                    unsafe { meos_sys::temporal_shift_time(self.inner(), ptr::addr_of!(interval)) };
+                if gap.is_zero() && i + 1 < times {
+                    unsafe {
+                        let trimmed = meos_sys::temporal_minus_timestamptz(
+                            copy,
+                            meos_sys::temporal_end_timestamptz(copy),
+                        );
+                        libc::free(copy as *mut c_void);
+                        (!trimmed.is_null()).then(|| factory::<Self::Enum>(trimmed))
+                    }
+                } else {
+                    Some(factory::<Self::Enum>(copy))
+                }
+            })
+            .collect();
+        Some(Self::Enum::from_merge(&copies))
+    }
+
     /// Returns a new `Temporal` downsampled with respect to `duration`.
     ///
     /// ## Arguments
@@ -338,7 +1103,7 @@ pub trait Temporal: Collection + Hash {
         start: DateTime<Tz>,
         interpolation: TInterpolation,
     ) -> Self {
-        let interval = create_interval(duration);
+        let interval = duration_to_interval(duration);
         Self::from_inner_as_temporal(unsafe {
             meos_sys::temporal_tsample(
                 self.inner(),
@@ -358,7 +1123,7 @@ pub trait Temporal: Collection + Hash {
     /// MEOS Functions:
     ///     `temporal_tprecision`
     fn temporal_precision<Tz: TimeZone>(self, duration: TimeDelta, start: DateTime<Tz>) -> Self {
-        let interval = create_interval(duration);
+        let interval = duration_to_interval(duration);
         Self::from_inner_as_temporal(unsafe {
             meos_sys::temporal_tprecision(
                 self.inner(),
@@ -408,10 +1173,18 @@ pub trait Temporal: Collection + Hash {
 
     /// Appends `instant` to `self`.
     ///
+    /// When `instant` is further than `max_dist` from the last value of `self`, or later than
+    /// `max_time` after its last timestamp, it starts a new sequence instead of extending the
+    /// last one, so the result is a sequence set with a gap.
+    ///
     /// ## Arguments
-    /// * `instant` - Instant to append.
-    /// * `max_dist` - Maximum distance for defining a gap.
-    /// * `max_time` - Maximum time for defining a gap.
+    /// * `instant` - Instant to append, after the end of `self`.
+    /// * `max_dist` - Maximum distance between consecutive values, or `None` for no limit.
+    /// * `max_time` - Maximum time between consecutive instants, or `None` for no limit.
+    ///
+    /// ## Returns
+    /// The extended temporal object, or a `MeosError` if `instant` is before the end of `self`,
+    /// or at its end with a different value.
     ///
     /// MEOS Functions:
     ///     `temporal_append_tinstant`
@@ -420,14 +1193,14 @@ pub trait Temporal: Collection + Hash {
         instant: Self::TI,
         max_dist: Option<f64>,
         max_time: Option<TimeDelta>,
-    ) -> Self::Enum {
-        let td = create_interval(max_time.unwrap_or_default());
+    ) -> Result<Self::Enum, MeosError> {
+        let td = duration_to_interval(max_time.unwrap_or_default());
         let max_time_ptr = if max_time.is_some() {
             ptr::addr_of!(td)
         } else {
             ptr::null()
         };
-        factory::<Self::Enum>(unsafe {
+        let (result, error) = capture_meos_error(|| unsafe {
             meos_sys::temporal_append_tinstant(
                 self.inner() as *mut _,
                 instant.inner_as_tinstant(),
@@ -435,7 +1208,11 @@ pub trait Temporal: Collection + Hash {
                 max_time_ptr,
                 false,
             )
-        })
+        });
+        match error {
+            Some(message) => Err(MeosError { message }),
+            None => Ok(factory::<Self::Enum>(result)),
+        }
     }
 
     /// Appends `sequence` to `self`.
@@ -468,30 +1245,50 @@ pub trait Temporal: Collection + Hash {
 
     /// Inserts `other` into `self`.
     ///
+    /// Where `self` and `other` share an instant, both must have the same value there.
+    ///
     /// ## Arguments
     /// * `other` - Temporal object to insert.
     /// * `connect` - Whether to connect inserted elements with existing ones.
     ///
+    /// ## Returns
+    /// The temporal object with `other` inserted, or a `MeosError` if `self` and `other` have
+    /// different values at a common instant.
+    ///
     /// MEOS Functions:
     ///     `temporal_insert`
-    fn insert(&self, other: Self::Enum, connect: bool) -> Self::Enum {
-        factory::<Self::Enum>(unsafe {
+    fn insert(&self, other: Self::Enum, connect: bool) -> Result<Self::Enum, MeosError> {
+        let (result, error) = capture_meos_error(|| unsafe {
             meos_sys::temporal_insert(self.inner(), other.inner(), connect)
-        })
+        });
+        match error {
+            Some(message) => Err(MeosError { message }),
+            None => Ok(factory::<Self::Enum>(result)),
+        }
     }
 
     /// Updates `self` with `other`.
     ///
+    /// The values of `self` during the time of `other` are replaced by those of `other`.
+    ///
     /// ## Arguments
     /// * `other` - Temporal object to update with.
     /// * `connect` - Whether to connect updated elements with existing ones.
     ///
+    /// ## Returns
+    /// The updated temporal object, or a `MeosError` if `other` cannot be merged into `self`,
+    /// e.g. because their interpolations differ.
+    ///
     /// MEOS Functions:
     ///     `temporal_update`
-    fn update(&self, other: Self::Enum, connect: bool) -> Self::Enum {
-        factory::<Self::Enum>(unsafe {
+    fn update(&self, other: Self::Enum, connect: bool) -> Result<Self::Enum, MeosError> {
+        let (result, error) = capture_meos_error(|| unsafe {
             meos_sys::temporal_update(self.inner(), other.inner(), connect)
-        })
+        });
+        match error {
+            Some(message) => Err(MeosError { message }),
+            None => Ok(factory::<Self::Enum>(result)),
+        }
     }
 
     /// Deletes elements from `self` at `other`.
@@ -500,12 +1297,20 @@ pub trait Temporal: Collection + Hash {
     /// * `other` - Time object specifying the elements to delete.
     /// * `connect` - Whether to connect the potential gaps generated by the deletions.
     ///
+    /// ## Returns
+    /// The remaining temporal object, or `None` if nothing is left.
+    ///
     /// MEOS Functions:
-    ///     `temporal_delete`
-    fn delete_at_timestamp<Tz: TimeZone>(&self, other: DateTime<Tz>, connect: bool) -> Self::Enum {
-        factory::<Self::Enum>(unsafe {
+    ///     `temporal_delete_timestamptz`
+    fn delete_at_timestamp<Tz: TimeZone>(
+        &self,
+        other: DateTime<Tz>,
+        connect: bool,
+    ) -> Option<Self::Enum> {
+        let result = unsafe {
             meos_sys::temporal_delete_timestamptz(self.inner(), to_meos_timestamp(&other), connect)
-        })
+        };
+        (!result.is_null()).then(|| factory::<Self::Enum>(result))
     }
 
     /// Deletes elements from `self` at `time_span`.
@@ -513,10 +1318,16 @@ pub trait Temporal: Collection + Hash {
     /// ## Arguments
     /// * `time_span` - Time span object specifying the elements to delete.
     /// * `connect` - Whether to connect the potential gaps generated by the deletions.
-    fn delete_at_tstz_span(&self, time_span: TsTzSpan, connect: bool) -> Self::Enum {
-        factory::<Self::Enum>(unsafe {
-            meos_sys::temporal_delete_tstzspan(self.inner(), time_span.inner(), connect)
-        })
+    ///
+    /// ## Returns
+    /// The remaining temporal object, or `None` if nothing is left.
+    ///
+    /// MEOS Functions:
+    ///     `temporal_delete_tstzspan`
+    fn delete_at_tstz_span(&self, time_span: TsTzSpan, connect: bool) -> Option<Self::Enum> {
+        let result =
+            unsafe { meos_sys::temporal_delete_tstzspan(self.inner(), time_span.inner(), connect) };
+        (!result.is_null()).then(|| factory::<Self::Enum>(result))
     }
 
     /// Deletes elements from `self` at `time_span_set`.
@@ -524,10 +1335,21 @@ pub trait Temporal: Collection + Hash {
     /// ## Arguments
     /// * `time_span_set` - Time span set object specifying the elements to delete.
     /// * `connect` - Whether to connect the potential gaps generated by the deletions.
-    fn delete_at_tstz_span_set(&self, time_span_set: TsTzSpanSet, connect: bool) -> Self::Enum {
-        factory::<Self::Enum>(unsafe {
+    ///
+    /// ## Returns
+    /// The remaining temporal object, or `None` if nothing is left.
+    ///
+    /// MEOS Functions:
+    ///     `temporal_delete_tstzspanset`
+    fn delete_at_tstz_span_set(
+        &self,
+        time_span_set: TsTzSpanSet,
+        connect: bool,
+    ) -> Option<Self::Enum> {
+        let result = unsafe {
             meos_sys::temporal_delete_tstzspanset(self.inner(), time_span_set.inner(), connect)
-        })
+        };
+        (!result.is_null()).then(|| factory::<Self::Enum>(result))
     }
 
     // ------------------------- Restrictions ----------------------------------
@@ -537,12 +1359,80 @@ pub trait Temporal: Collection + Hash {
     /// ## Arguments
     /// * `other` - A timestamp to restrict the values to.
     ///
+    /// ## Returns
+    /// The instant of `self` at `other`, or `None` if `self` is not defined at `other`.
+    ///
     /// MEOS Functions:
-    ///     `temporal_at_temporal_at_timestamptz`
-    fn at_timestamp<Tz: TimeZone>(&self, other: DateTime<Tz>) -> Self::TI {
-        <Self::TI as Temporal>::from_inner_as_temporal(unsafe {
-            meos_sys::temporal_at_timestamptz(self.inner(), to_meos_timestamp(&other))
-        })
+    ///     `temporal_at_timestamptz`
+    fn at_timestamp<Tz: TimeZone>(&self, other: DateTime<Tz>) -> Option<Self::TI> {
+        let result =
+            unsafe { meos_sys::temporal_at_timestamptz(self.inner(), to_meos_timestamp(&other)) };
+        (!result.is_null()).then(|| <Self::TI as Temporal>::from_inner_as_temporal(result))
+    }
+
+    /// Returns a new temporal object with values restricted to the time `span`.
+    ///
+    /// ## Arguments
+    /// * `span` - A time span to restrict the values to.
+    ///
+    /// ## Returns
+    /// The restricted temporal object, or `None` if `self` is not defined during `span`.
+    ///
+    /// MEOS Functions:
+    ///     `temporal_at_tstzspan`
+    fn at_time(&self, span: &TsTzSpan) -> Option<Self> {
+        let result = unsafe { meos_sys::temporal_at_tstzspan(self.inner(), span.inner()) };
+        (!result.is_null()).then(|| Self::from_inner_as_temporal(result))
+    }
+
+    /// Returns a new temporal object with values restricted to the time `span_set`.
+    ///
+    /// As restricting a sequence to several spans may split it, the result is a `Self::Enum`.
+    ///
+    /// ## Arguments
+    /// * `span_set` - A time span set to restrict the values to.
+    ///
+    /// ## Returns
+    /// The restricted temporal object, or `None` if `self` is not defined during `span_set`.
+    ///
+    /// MEOS Functions:
+    ///     `temporal_at_tstzspanset`
+    fn at_time_set(&self, span_set: &TsTzSpanSet) -> Option<Self::Enum> {
+        let result = unsafe { meos_sys::temporal_at_tstzspanset(self.inner(), span_set.inner()) };
+        (!result.is_null()).then(|| factory::<Self::Enum>(result))
+    }
+
+    /// Returns a new temporal object restricted to a daily time window, e.g. to keep business
+    /// hours only.
+    ///
+    /// The window is taken in UTC on every day of the domain of `self`, bounds included. When
+    /// `to` is before `from`, the window spans midnight, e.g. 22:00 to 06:00.
+    ///
+    /// ## Arguments
+    /// * `from` - Time of the day at which the window starts.
+    /// * `to` - Time of the day at which the window ends.
+    ///
+    /// ## Returns
+    /// The restricted temporal object, or `None` if `self` is never defined within the window.
+    ///
+    /// MEOS Functions:
+    ///     `temporal_at_tstzspanset`
+    fn at_time_of_day(&self, from: NaiveTime, to: NaiveTime) -> Option<Self::Enum> {
+        let first_day = self.start_timestamp().date_naive().pred_opt().unwrap();
+        let last_day = self.end_timestamp().date_naive();
+        let windows: Vec<TsTzSpan> = first_day
+            .iter_days()
+            .take_while(|day| *day <= last_day)
+            .map(|day| {
+                let end_day = if from <= to {
+                    day
+                } else {
+                    day.succ_opt().unwrap()
+                };
+                (day.and_time(from).and_utc()..=end_day.and_time(to).and_utc()).into()
+            })
+            .collect();
+        self.at_time_set(&windows.iter().collect())
     }
 
     /// Returns a new temporal object with values restricted to the time `time_span`.
@@ -583,6 +1473,39 @@ pub trait Temporal: Collection + Hash {
     ///     `temporal_at_values`
     fn at_values(&self, values: &[Self::Type]) -> Option<Self::Enum>;
 
+    /// Returns the first timestamp at which `self` takes the value `value`.
+    ///
+    /// ## Arguments
+    /// * `value` - The value to look for.
+    ///
+    /// ## Returns
+    /// The first timestamp, or `None` if `self` never takes the value.
+    ///
+    /// MEOS Functions:
+    ///     `temporal_at_value`, `temporal_start_timestamptz`
+    fn first_time_at_value(&self, value: &Self::Type) -> Option<DateTime<Utc>> {
+        self.at_value(value)
+            .map(|restricted| restricted.start_timestamp())
+    }
+
+    /// Returns the last timestamp at which `self` takes the value `value`.
+    ///
+    /// For continuous interpolations this is the end of the last period where `self` holds
+    /// the value, which may be an exclusive bound.
+    ///
+    /// ## Arguments
+    /// * `value` - The value to look for.
+    ///
+    /// ## Returns
+    /// The last timestamp, or `None` if `self` never takes the value.
+    ///
+    /// MEOS Functions:
+    ///     `temporal_at_value`, `temporal_end_timestamptz`
+    fn last_time_at_value(&self, value: &Self::Type) -> Option<DateTime<Utc>> {
+        self.at_value(value)
+            .map(|restricted| restricted.end_timestamp())
+    }
+
     /// Returns a new temporal object with values at `timestamp` removed.
     ///
     /// ## Arguments
@@ -629,6 +1552,39 @@ pub trait Temporal: Collection + Hash {
         })
     }
 
+    /// Returns a new temporal object with values at `span` removed.
+    ///
+    /// As removing a span from a sequence may split it, the result is a `Self::Enum`.
+    ///
+    /// ## Arguments
+    /// * `span` - A time span specifying the values to remove.
+    ///
+    /// ## Returns
+    /// The restricted temporal object, or `None` if `self` is only defined during `span`.
+    ///
+    /// MEOS Functions:
+    ///     `temporal_minus_tstzspan`
+    fn minus_time(&self, span: &TsTzSpan) -> Option<Self::Enum> {
+        let result = unsafe { meos_sys::temporal_minus_tstzspan(self.inner(), span.inner()) };
+        (!result.is_null()).then(|| factory::<Self::Enum>(result))
+    }
+
+    /// Returns a new temporal object with values at `span_set` removed.
+    ///
+    /// ## Arguments
+    /// * `span_set` - A time span set specifying the values to remove.
+    ///
+    /// ## Returns
+    /// The restricted temporal object, or `None` if `self` is only defined during `span_set`.
+    ///
+    /// MEOS Functions:
+    ///     `temporal_minus_tstzspanset`
+    fn minus_time_set(&self, span_set: &TsTzSpanSet) -> Option<Self::Enum> {
+        let result =
+            unsafe { meos_sys::temporal_minus_tstzspanset(self.inner(), span_set.inner()) };
+        (!result.is_null()).then(|| factory::<Self::Enum>(result))
+    }
+
     /// Returns a new temporal object containing the times `self` is not at `value`.
     ///
     /// MEOS Functions:
@@ -838,7 +1794,7 @@ pub trait Temporal: Collection + Hash {
     /// MEOS Functions:
     ///     `temporal_time_split`
     fn time_split<Tz: TimeZone>(&self, duration: TimeDelta, start: DateTime<Tz>) -> Vec<Self> {
-        let duration = create_interval(duration);
+        let duration = duration_to_interval(duration);
         let start = to_meos_timestamp(&start);
         let mut count = 0;
         let _buckets = Vec::new().as_mut_ptr();
@@ -874,6 +1830,46 @@ pub trait Temporal: Collection + Hash {
         self.time_split(duration, start)
     }
 
+    /// Splits the temporal object into `n` contiguous parts with roughly the same number of instants.
+    ///
+    /// When the number of instants is not a multiple of `n`, the first `num_instants % n` parts
+    /// receive one extra instant. If `n` is greater than the number of instants, one part per
+    /// instant is returned, and if `n` is zero no parts are returned.
+    ///
+    /// Every part but the last one runs from its first instant until the first instant of the
+    /// next part, exclusive, so the parts together cover the whole time of `self` and a
+    /// continuous part ends with the value interpolated at that bound.
+    ///
+    /// ## Arguments
+    /// * `n` - Number of parts to split into.
+    ///
+    /// ## Returns
+    /// A list of temporal objects, in time order.
+    ///
+    /// MEOS Functions:
+    ///     `temporal_timestamps`, `temporal_at_tstzspan`
+    fn chunks(&self, n: usize) -> Vec<Self> {
+        let timestamps = self.timestamps();
+        if n == 0 || timestamps.is_empty() {
+            return Vec::new();
+        }
+        let n = n.min(timestamps.len());
+        let (size, remainder) = (timestamps.len() / n, timestamps.len() % n);
+        let mut start = 0;
+        (0..n)
+            .map(|i| {
+                let end = start + size + usize::from(i < remainder);
+                let span: TsTzSpan = if end < timestamps.len() {
+                    (timestamps[start]..timestamps[end]).into()
+                } else {
+                    (timestamps[start]..=timestamps[end - 1]).into()
+                };
+                start = end;
+                self.at_tstz_span(span)
+            })
+            .collect()
+    }
+
     /// Extracts the subsequences where the object stays within a certain distance for a specified duration.
     ///
     /// ## Arguments
@@ -886,7 +1882,7 @@ pub trait Temporal: Collection + Hash {
     /// MEOS Functions:
     ///     `temporal_stops`
     fn stops(&self, max_distance: f64, min_duration: TimeDelta) -> Self::TSS {
-        let interval = create_interval(min_duration);
+        let interval = duration_to_interval(min_duration);
         unsafe {
             <Self::TSS as TSequenceSet>::from_inner(meos_sys::temporal_stops(
                 self.inner(),
@@ -1486,7 +2482,7 @@ pub trait SimplifiableTemporal: Temporal {
     ///
     /// This method wraps the `temporal_simplify_min_tdelta` function from MEOS.
     fn simplify_min_tdelta(&self, distance: TimeDelta) -> Self::Enum {
-        let interval = create_interval(distance);
+        let interval = duration_to_interval(distance);
         factory::<Self::Enum>(unsafe {
             meos_sys::temporal_simplify_min_tdelta(self.inner(), ptr::addr_of!(interval))
         })
@@ -1496,9 +2492,11 @@ pub trait SimplifiableTemporal: Temporal {
     ///
     /// # Arguments
     ///
-    /// * `distance` - A `f64` representing the minimum distance between two points.
-    /// * `synchronized` - A `bool` indicating if the Synchronized Distance should be used.
-    ///   If `false`, the spatial-only distance will be used.
+    /// * `distance` - A `f64` representing the maximum distance between a removed instant and
+    ///   the simplified value.
+    /// * `synchronized` - A `bool` indicating if the Synchronized Euclidean Distance (SED),
+    ///   measured at the same timestamp, should be used. If `false`, the spatial-only distance
+    ///   of the classic Douglas-Peucker algorithm will be used.
     ///
     /// # Returns
     ///
@@ -1517,9 +2515,11 @@ pub trait SimplifiableTemporal: Temporal {
     ///
     /// # Arguments
     ///
-    /// * `distance` - A `f64` representing the minimum distance between two points.
-    /// * `synchronized` - A `bool` indicating if the Synchronized Distance should be used.
-    ///   If `false`, the spatial-only distance will be used.
+    /// * `distance` - A `f64` representing the maximum distance between a removed instant and
+    ///   the simplified value.
+    /// * `synchronized` - A `bool` indicating if the Synchronized Euclidean Distance (SED),
+    ///   measured at the same timestamp, should be used. If `false`, the spatial-only distance
+    ///   will be used.
     ///
     /// # Returns
     ///
@@ -1578,6 +2578,32 @@ macro_rules! impl_simple_traits_for_temporal {
     }
 }
 
+/// Implements `Eq` and `Ord` with `temporal_cmp` for the temporal types whose values MEOS
+/// compares with a total order, that is, booleans, integers, floats and texts.
+macro_rules! impl_ordering_for_temporal {
+    ($type:ty) => {
+        impl Eq for $type {}
+
+        /// Temporals are ordered with the canonical MEOS ordering, that is, by time and then by
+        /// value. Float values are compared like in PostgreSQL rather than with IEEE comparison,
+        /// so NaN equals itself and is greater than any other value.
+        impl PartialOrd for $type {
+            fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+
+        /// Temporals are ordered with the canonical MEOS ordering, that is, by time and then by
+        /// value. Float values are compared like in PostgreSQL rather than with IEEE comparison,
+        /// so NaN equals itself and is greater than any other value.
+        impl Ord for $type {
+            fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+                unsafe { meos_sys::temporal_cmp(self.inner(), other.inner()) }.cmp(&0)
+            }
+        }
+    };
+}
+
 macro_rules! impl_always_and_ever_value_equality_functions {
     ($type:ident, $transform_function:expr) => {
         paste::paste! {
@@ -1722,3 +2748,5 @@ pub(crate) use impl_ordered_temporal_functions;
 pub(crate) use impl_always_and_ever_value_equality_functions;
 
 pub(crate) use impl_simple_traits_for_temporal;
+
+pub(crate) use impl_ordering_for_temporal;
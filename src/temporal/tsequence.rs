@@ -1,4 +1,7 @@
+use std::{ffi::c_void, marker::PhantomData, mem, ptr};
+
 use super::{interpolation::TInterpolation, temporal::Temporal, tinstant::TInstant};
+use crate::{capture_meos_error, errors::MeosError};
 
 pub trait TSequence: Temporal {
     /// ## Arguments
@@ -33,6 +36,49 @@ pub trait TSequence: Temporal {
         })
     }
 
+    /// Creates a sequence from `values` with explicit bound inclusivity, e.g. to build a
+    /// sequence open at its start.
+    ///
+    /// ## Arguments
+    /// * `values` - A slice of temporal instants, in time order.
+    /// * `interpolation` - The interpolation method to use for the temporal sequence.
+    /// * `lower_inclusive` - Whether the sequence includes its first instant.
+    /// * `upper_inclusive` - Whether the sequence includes its last instant.
+    ///
+    /// ## Returns
+    /// The sequence, or a `MeosError` if MEOS rejects it, e.g. because the instants are not in
+    /// time order or a discrete or instantaneous sequence has an exclusive bound.
+    ///
+    /// MEOS Functions:
+    ///     `tsequence_make`
+    fn new_with_bounds<Inst: AsRef<Self::TI>>(
+        values: &[Inst],
+        interpolation: TInterpolation,
+        lower_inclusive: bool,
+        upper_inclusive: bool,
+    ) -> Result<Self, MeosError> {
+        let mut t_list: Vec<_> = values
+            .iter()
+            .map(|i| i.as_ref().inner_as_tinstant())
+            .collect();
+        let (inner, error) = capture_meos_error(|| unsafe {
+            meos_sys::tsequence_make(
+                t_list.as_mut_ptr(),
+                t_list.len() as i32,
+                lower_inclusive,
+                upper_inclusive,
+                interpolation as u32,
+                true,
+            )
+        });
+        match error {
+            None if !inner.is_null() => Ok(TSequence::from_inner(inner)),
+            error => Err(MeosError {
+                message: error.unwrap_or_else(|| "Could not create the sequence".to_owned()),
+            }),
+        }
+    }
+
     fn from_inner(inner: *mut meos_sys::TSequence) -> Self;
     fn inner_mut_as_tsequence(&self) -> *mut meos_sys::TSequence;
     fn inner_as_tsequence(&self) -> *const meos_sys::TSequence {
@@ -46,3 +92,90 @@ pub trait TSequence: Temporal {
         unsafe { meos_sys::temporal_upper_inc(self.inner()) }
     }
 }
+
+/// Incrementally builds a temporal sequence from instants.
+///
+/// Instants are appended in place to an expandable MEOS sequence, which grows its storage
+/// geometrically, so building a sequence of `n` instants takes amortized linear time instead of
+/// rebuilding the whole sequence on every append.
+pub struct SequenceBuilder<T: TSequence> {
+    inner: *mut meos_sys::Temporal,
+    interpolation: TInterpolation,
+    len: usize,
+    _sequence: PhantomData<T>,
+}
+
+impl<T: TSequence> SequenceBuilder<T> {
+    /// Creates an empty builder.
+    ///
+    /// ## Arguments
+    /// * `interpolation` - The interpolation of the sequence being built.
+    pub fn new(interpolation: TInterpolation) -> Self {
+        Self {
+            inner: ptr::null_mut(),
+            interpolation,
+            len: 0,
+            _sequence: PhantomData,
+        }
+    }
+
+    /// Appends `instant` at the end of the sequence being built.
+    ///
+    /// ## Arguments
+    /// * `instant` - The instant to append, which must be after the last appended one.
+    ///
+    /// MEOS Functions:
+    ///     `tsequence_make`, `temporal_append_tinstant`
+    pub fn push(&mut self, instant: &T::TI) {
+        let instant = instant.inner_as_tinstant();
+        if self.inner.is_null() {
+            let mut instants = [instant];
+            self.inner = unsafe {
+                meos_sys::tsequence_make(
+                    instants.as_mut_ptr(),
+                    1,
+                    true,
+                    true,
+                    self.interpolation as u32,
+                    false,
+                )
+            } as *mut meos_sys::Temporal;
+        } else {
+            let appended = unsafe {
+                meos_sys::temporal_append_tinstant(self.inner, instant, 0.0, ptr::null(), true)
+            };
+            if appended != self.inner {
+                unsafe { libc::free(self.inner as *mut c_void) };
+                self.inner = appended;
+            }
+        }
+        self.len += 1;
+    }
+
+    /// Returns the number of instants appended so far.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns whether no instant has been appended yet.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the built sequence.
+    ///
+    /// ## Panics
+    /// If no instant was appended.
+    pub fn finish(mut self) -> T {
+        let inner = mem::replace(&mut self.inner, ptr::null_mut());
+        T::from_inner(inner as *mut meos_sys::TSequence)
+    }
+}
+
+impl<T: TSequence> Drop for SequenceBuilder<T> {
+    fn drop(&mut self) {
+        if !self.inner.is_null() {
+            unsafe { libc::free(self.inner as *mut c_void) };
+        }
+    }
+}
@@ -1,4 +1,7 @@
+use std::ffi::c_void;
+
 use super::{temporal::Temporal, tsequence::TSequence};
+use crate::{capture_meos_error, errors::MeosError};
 
 pub trait TSequenceSet: Temporal {
     /// ## Arguments
@@ -15,4 +18,71 @@ pub trait TSequenceSet: Temporal {
     }
 
     fn from_inner(inner: *mut meos_sys::TSequenceSet) -> Self;
+
+    /// Returns the number of sequences in the sequence set.
+    ///
+    /// MEOS Functions:
+    ///     `temporal_num_sequences`
+    fn num_sequences(&self) -> i32 {
+        unsafe { meos_sys::temporal_num_sequences(self.inner()) }
+    }
+
+    /// Returns the number of instants of all the sequences of the sequence set.
+    ///
+    /// Unlike `num_instants`, an instant shared by the end of a sequence and the start of
+    /// the next one is counted twice.
+    fn num_total_instants(&self) -> i32 {
+        unsafe { (*(self.inner() as *const meos_sys::TSequenceSet)).totalcount }
+    }
+
+    /// Appends `sequence` to `self`, checking that both are compatible.
+    ///
+    /// ## Arguments
+    /// * `sequence` - Sequence to append. It must have the same interpolation as `self` and
+    ///   must not start before the end of `self`.
+    ///
+    /// ## Returns
+    /// A new sequence set with `sequence` appended, or a `MeosError` if `sequence` cannot be
+    /// appended to `self`.
+    ///
+    /// MEOS Functions:
+    ///     `temporal_append_tsequence`
+    fn try_append_sequence(&self, sequence: &Self::TS) -> Result<Self, MeosError> {
+        if sequence.interpolation() != self.interpolation() {
+            return Err(MeosError {
+                message: format!(
+                    "Cannot append a sequence with {:?} interpolation to a sequence set with {:?} interpolation",
+                    sequence.interpolation(),
+                    self.interpolation()
+                ),
+            });
+        }
+        if sequence.start_timestamp() < self.end_timestamp() {
+            return Err(MeosError {
+                message: format!(
+                    "Cannot append a sequence starting at {} to a sequence set ending at {}",
+                    sequence.start_timestamp(),
+                    self.end_timestamp()
+                ),
+            });
+        }
+        let (inner, error) = capture_meos_error(|| unsafe {
+            meos_sys::temporal_append_tsequence(
+                self.inner() as *mut _,
+                sequence.inner_as_tsequence(),
+                false,
+            )
+        });
+        match error {
+            None if !inner.is_null() => Ok(Self::from_inner(inner as *mut _)),
+            error => {
+                if !inner.is_null() {
+                    unsafe { libc::free(inner as *mut c_void) };
+                }
+                Err(MeosError {
+                    message: error.unwrap_or_else(|| "Could not append the sequence".to_owned()),
+                })
+            }
+        }
+    }
 }
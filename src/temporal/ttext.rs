@@ -13,13 +13,14 @@ use crate::{
         base::{collection::Collection, span::Span, span_set::SpanSet},
         datetime::{tstz_span::TsTzSpan, tstz_span_set::TsTzSpanSet},
     },
-    errors::ParseError,
-    factory, impl_from_str,
+    errors::{ParseError, TemporalParseError},
+    factory, impl_from_str, impl_serde, parse_mfjson,
     temporal::{
+        number::tint::{TInt, TIntInstant, TIntSequence, TIntSequenceSet},
         tbool::{TBoolInstant, TBoolSequence, TBoolSequenceSet},
         temporal::{
             impl_always_and_ever_value_equality_functions, impl_ordered_temporal_functions,
-            impl_simple_traits_for_temporal, OrderedTemporal, Temporal,
+            impl_ordering_for_temporal, impl_simple_traits_for_temporal, OrderedTemporal, Temporal,
         },
         tinstant::TInstant,
         tsequence::TSequence,
@@ -104,6 +105,7 @@ macro_rules! impl_ttext_traits {
             }
 
             impl_simple_traits_for_temporal!($type, with_drop);
+            impl_ordering_for_temporal!($type);
             impl_debug!($type);
 
             impl OrderedTemporal for $type {
@@ -237,6 +239,57 @@ pub enum TText {
 }
 
 impl_from_str!(TText);
+impl_serde!(TText, |temporal| unsafe {
+    meos_sys::ttext_out(temporal.inner())
+});
+
+impl TText {
+    /// Returns a temporal integer with the number of characters of the value at each instant.
+    ///
+    /// ## Returns
+    /// A `TInt` with the same temporal structure as `self`.
+    pub fn length(&self) -> TInt {
+        match self {
+            TText::Instant(instant) => TInt::Instant(length_instant(instant)),
+            TText::Sequence(sequence) => TInt::Sequence(length_sequence(sequence)),
+            TText::SequenceSet(_) => {
+                let mut count = 0;
+                let sequences: Vec<TIntSequence> = unsafe {
+                    let sequences =
+                        meos_sys::temporal_sequences(self.inner(), ptr::addr_of_mut!(count));
+                    Vec::from_raw_parts(sequences, count as usize, count as usize)
+                        .into_iter()
+                        .map(TTextSequence::from_inner)
+                        .map(|sequence| length_sequence(&sequence))
+                        .collect()
+                };
+                TInt::SequenceSet(TIntSequenceSet::new(&sequences, false))
+            }
+        }
+    }
+}
+
+fn length_instant(instant: &TTextInstant) -> TIntInstant {
+    TIntInstant::from_value_and_timestamp(
+        instant.value().chars().count() as i32,
+        instant.timestamp(),
+    )
+}
+
+fn length_sequence(sequence: &TTextSequence) -> TIntSequence {
+    let lengths: Vec<TIntInstant> = sequence.instants().iter().map(length_instant).collect();
+    let mut t_list: Vec<_> = lengths.iter().map(TInstant::inner_as_tinstant).collect();
+    TIntSequence::from_inner(unsafe {
+        meos_sys::tsequence_make(
+            t_list.as_mut_ptr(),
+            t_list.len() as i32,
+            meos_sys::temporal_lower_inc(sequence.inner()),
+            meos_sys::temporal_upper_inc(sequence.inner()),
+            sequence.interpolation() as u32,
+            true,
+        )
+    })
+}
 
 impl MeosEnum for TText {
     fn from_instant(inner: *mut meos_sys::TInstant) -> Self {
@@ -251,9 +304,8 @@ impl MeosEnum for TText {
         Self::SequenceSet(TTextSequenceSet::from_inner(inner))
     }
 
-    fn from_mfjson(mfjson: &str) -> Self {
-        let cstr = CString::new(mfjson).unwrap();
-        factory::<Self>(unsafe { meos_sys::ttext_from_mfjson(cstr.as_ptr()) })
+    fn from_mfjson(mfjson: &str) -> Result<Self, TemporalParseError> {
+        parse_mfjson(mfjson, meos_sys::ttext_from_mfjson)
     }
 }
 
@@ -479,6 +531,7 @@ impl Collection for TText {
 }
 
 impl_simple_traits_for_temporal!(TText);
+impl_ordering_for_temporal!(TText);
 
 impl OrderedTemporal for TText {
     fn min_value(&self) -> Self::Type {
@@ -640,4 +693,37 @@ mod tests {
             format!("SequenceSet({})", string.to_owned())
         );
     }
+
+    #[test]
+    fn length_ttext() {
+        meos_initialize("UTC");
+        let messages: TText = "{\"AAA\"@2018-01-01 08:00:00+00, \"BBBB\"@2018-01-01 09:00:00+00}"
+            .parse()
+            .unwrap();
+        let expected: TInt = "{3@2018-01-01 08:00:00+00, 4@2018-01-01 09:00:00+00}"
+            .parse()
+            .unwrap();
+        assert_eq!(messages.length(), expected);
+    }
+
+    #[test]
+    fn mode_ttext() {
+        meos_initialize("UTC");
+        let ttext: TText = "[\"idle\"@2020-01-01, \"moving\"@2020-01-02, \"idle\"@2020-01-05, \"idle\"@2020-01-06]"
+            .parse()
+            .unwrap();
+        assert_eq!(ttext.mode(), Some("moving".to_owned()));
+    }
+
+    #[test]
+    fn to_string_in_tz_ttext() {
+        meos_initialize("UTC");
+        let ttext: TText = "{\"a@b\"@2018-01-01 08:00:00+00, \"\\\"@\"@2018-01-01 09:00:00+00}"
+            .parse()
+            .unwrap();
+        assert_eq!(
+            ttext.to_string_in_tz(&chrono_tz::Europe::Paris),
+            "Sequence({\"a@b\"@2018-01-01 09:00:00+01, \"\\\"@\"@2018-01-01 10:00:00+01})"
+        );
+    }
 }
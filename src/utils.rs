@@ -1,25 +1,45 @@
-use chrono::{DateTime, TimeZone, Utc};
+use chrono::{DateTime, Offset, TimeDelta, TimeZone, Timelike, Utc};
 
 use crate::collections::datetime::MICROSECONDS_UNTIL_2000;
 
-pub(crate) fn create_interval(t: chrono::TimeDelta) -> meos_sys::Interval {
-    let time_in_microseconds = t.num_microseconds().unwrap_or(0);
-    let total_days = t.num_days() as i32;
+/// Converts a `TimeDelta` into a MEOS `Interval`.
+///
+/// Like PostgreSQL, MEOS stores intervals as three separate fields: a number of months, a number
+/// of days and a number of microseconds. The whole days of `duration` are stored in `day` and the
+/// remaining microseconds in `time`, while `month` is always zero.
+///
+/// ## Arguments
+/// * `duration` - The duration to convert.
+///
+/// ## Returns
+/// The equivalent `meos_sys::Interval`.
+pub fn duration_to_interval(duration: TimeDelta) -> meos_sys::Interval {
+    let days = duration.num_days();
+    let remainder = duration - TimeDelta::days(days);
 
     meos_sys::Interval {
-        time: time_in_microseconds,
-        day: total_days,
+        time: remainder.num_microseconds().unwrap_or(0),
+        day: days as i32,
         month: 0,
     }
 }
 
-pub(crate) fn from_interval(interval: meos_sys::Interval) -> chrono::TimeDelta {
+/// Converts a MEOS `Interval` into a `TimeDelta`.
+///
+/// The `month`, `day` and `time` (microseconds) fields are added together, assuming 30 days per
+/// month as MEOS does when it needs to compare intervals.
+///
+/// ## Arguments
+/// * `interval` - The interval to convert.
+///
+/// ## Returns
+/// The equivalent `TimeDelta`.
+pub fn interval_to_duration(interval: meos_sys::Interval) -> TimeDelta {
     let time_in_microseconds = interval.time;
     let days = interval.day as i64;
     let months = interval.month as i64;
 
-    chrono::TimeDelta::microseconds(time_in_microseconds)
-        + chrono::TimeDelta::days(days + months * 30) // meos assumes 30 days per month
+    TimeDelta::microseconds(time_in_microseconds) + TimeDelta::days(days + months * 30)
 }
 
 pub(crate) fn to_meos_timestamp<Tz: TimeZone>(dt: &DateTime<Tz>) -> i64 {
@@ -30,3 +50,69 @@ pub(crate) fn from_meos_timestamp(timestamp: meos_sys::TimestampTz) -> DateTime<
     DateTime::from_timestamp_micros(timestamp + MICROSECONDS_UNTIL_2000)
         .expect("Failed to parse DateTime")
 }
+
+/// Formats `timestamp` in the timezone `tz` the way MEOS prints a `timestamptz`, e.g.
+/// `2019-07-08 12:00:00.5+02`: fractional seconds only when present and the UTC offset in
+/// hours, followed by minutes and seconds only when they are not zero.
+pub(crate) fn format_timestamp<Tz: TimeZone>(timestamp: &DateTime<Utc>, tz: &Tz) -> String {
+    let local = timestamp.with_timezone(tz);
+    let mut text = local.naive_local().format("%Y-%m-%d %H:%M:%S").to_string();
+    let microseconds = local.nanosecond() % 1_000_000_000 / 1_000;
+    if microseconds != 0 {
+        let fraction = format!("{microseconds:06}");
+        text.push('.');
+        text.push_str(fraction.trim_end_matches('0'));
+    }
+    let offset = local.offset().fix().local_minus_utc();
+    let sign = if offset < 0 { '-' } else { '+' };
+    let offset = offset.abs();
+    text.push_str(&format!("{sign}{:02}", offset / 3600));
+    if offset % 3600 != 0 {
+        text.push_str(&format!(":{:02}", offset / 60 % 60));
+    }
+    if offset % 60 != 0 {
+        text.push_str(&format!(":{:02}", offset % 60));
+    }
+    text
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn interval_round_trip() {
+        let duration = TimeDelta::days(400) + TimeDelta::microseconds(1_500);
+        let interval = duration_to_interval(duration);
+        assert_eq!(
+            (interval.month, interval.day, interval.time),
+            (0, 400, 1_500)
+        );
+        assert_eq!(interval_to_duration(interval), duration);
+
+        let duration = TimeDelta::days(400);
+        assert_eq!(
+            interval_to_duration(duration_to_interval(duration)),
+            duration
+        );
+    }
+
+    #[test]
+    fn format_timestamp_offsets() {
+        let timestamp = DateTime::parse_from_rfc3339("2019-07-08T10:00:00.5Z")
+            .unwrap()
+            .to_utc();
+        assert_eq!(
+            format_timestamp(&timestamp, &Utc),
+            "2019-07-08 10:00:00.5+00"
+        );
+        assert_eq!(
+            format_timestamp(&timestamp, &chrono_tz::Europe::Paris),
+            "2019-07-08 12:00:00.5+02"
+        );
+        assert_eq!(
+            format_timestamp(&timestamp, &chrono_tz::America::St_Johns),
+            "2019-07-08 07:30:00.5-02:30"
+        );
+    }
+}
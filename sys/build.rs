@@ -8,6 +8,10 @@ fn detect_meos_via_pkg_config() -> bool {
 
     let meos_pkg_config = Config::new().probe("meos");
 
+    if let Ok(library) = &meos_pkg_config {
+        println!("cargo:rustc-env=MEOS_LIBRARY_VERSION={}", library.version);
+    }
+
     meos_pkg_config.is_ok()
 }
 
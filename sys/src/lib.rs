@@ -14,3 +14,10 @@
 #![allow(non_snake_case)]
 
 include!("../prebuilt-bindings/meos_1.1.rs");
+
+/// Version of the MEOS C API these bindings were generated from.
+pub const BINDINGS_VERSION: &str = "1.1";
+
+/// Version of the MEOS library reported by pkg-config at build time, or `None` if the library
+/// was found through `MEOS_LIB_DIR` instead.
+pub const LIBRARY_VERSION: Option<&str> = option_env!("MEOS_LIBRARY_VERSION");